@@ -2,7 +2,11 @@
 //!
 //! This module provides the core color types used throughout the brand system.
 
-use std::fmt;
+use core::fmt;
+use core::ops::{Add, Mul, Sub};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::borrow::Cow;
 
 /// An RGB color with 8-bit components.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -23,26 +27,47 @@ impl Rgb {
         Self { r, g, b }
     }
 
-    /// Create an RGB color from a hex string (without #).
+    /// Create an RGB color from normalized channels (0.0 - 1.0, clamped),
+    /// rounding to the nearest byte. The inverse of [`Rgb::to_f32`].
+    #[must_use]
+    pub fn new_f32(r: f32, g: f32, b: f32) -> Self {
+        Self::new(to_byte(r), to_byte(g), to_byte(b))
+    }
+
+    /// Create an RGB color from a hex string (`#` optional), dropping any
+    /// alpha digits.
+    ///
+    /// Use [`Color::try_from_hex`] for untrusted input.
     ///
     /// # Panics
     ///
     /// Panics if the hex string is invalid.
     #[must_use]
     pub fn from_hex(hex: &str) -> Self {
-        let hex = hex.trim_start_matches('#');
-        let r = u8::from_str_radix(&hex[0..2], 16).expect("Invalid hex");
-        let g = u8::from_str_radix(&hex[2..4], 16).expect("Invalid hex");
-        let b = u8::from_str_radix(&hex[4..6], 16).expect("Invalid hex");
-        Self { r, g, b }
+        match Color::try_from_hex(hex) {
+            Ok(color) => color.to_rgb(),
+            Err(err) => panic!("Invalid hex: {err}"),
+        }
     }
 
     /// Convert to a hex string with # prefix.
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn hex(&self) -> String {
         format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
     }
 
+    /// Write the hex form (with # prefix) to any [`fmt::Write`] sink.
+    ///
+    /// This is the allocation-free alternative to [`Rgb::hex`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn write_hex<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        write!(out, "#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+
     /// Convert to an RGBA color with full opacity.
     #[must_use]
     pub const fn to_rgba(self) -> Rgba {
@@ -92,15 +117,24 @@ impl Rgba {
         Self { r, g, b, a }
     }
 
+    /// Create an RGBA color from normalized channels (0.0 - 1.0, clamped),
+    /// rounding to the nearest byte.
+    #[must_use]
+    pub fn new_f32(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::new(to_byte(r), to_byte(g), to_byte(b), to_byte(a))
+    }
+
     /// Create an RGBA color from RGB with a given opacity (0.0 - 1.0).
+    ///
+    /// The alpha byte is rounded to nearest, so 0.4 gives 102 and 0.5
+    /// gives 128, matching [`Color::at_opacity`] and the TypeScript tokens.
     #[must_use]
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     pub fn from_rgb_alpha(rgb: Rgb, alpha: f32) -> Self {
         Self {
             r: rgb.r,
             g: rgb.g,
             b: rgb.b,
-            a: (alpha.clamp(0.0, 1.0) * 255.0) as u8,
+            a: to_byte(alpha),
         }
     }
 
@@ -121,6 +155,7 @@ impl Rgba {
     }
 
     /// Convert to CSS `rgba()` format.
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn css(&self) -> String {
         format!(
@@ -134,10 +169,12 @@ impl Rgba {
 }
 
 impl fmt::Display for Rgba {
+    /// Alpha is printed with three decimals, enough for every alpha byte to
+    /// parse back unchanged.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "rgba({}, {}, {}, {:.2})",
+            "rgba({}, {}, {}, {:.3})",
             self.r,
             self.g,
             self.b,
@@ -169,13 +206,41 @@ impl Color {
         Self::Rgba(Rgba::new(r, g, b, a))
     }
 
+    /// Create an opaque color from a `0xRRGGBB` integer. The top byte is
+    /// ignored.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_rgb_u32(value: u32) -> Self {
+        Self::rgb((value >> 16) as u8, (value >> 8) as u8, value as u8)
+    }
+
+    /// Create a color from a `0xRRGGBBAA` integer.
+    ///
+    /// Use this rather than `Color::from(u32)` when the value always carries
+    /// alpha, since a red channel of 0 would otherwise read as RGB.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_rgba_u32(value: u32) -> Self {
+        Self::rgba(
+            (value >> 24) as u8,
+            (value >> 16) as u8,
+            (value >> 8) as u8,
+            value as u8,
+        )
+    }
+
     /// Create a color from a hex string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hex string is invalid; see [`Color::try_from_hex`].
     #[must_use]
     pub fn from_hex(hex: &str) -> Self {
         Self::Rgb(Rgb::from_hex(hex))
     }
 
     /// Convert to hex string.
+    #[cfg(feature = "std")]
     #[must_use]
     pub fn hex(&self) -> String {
         match self {
@@ -184,6 +249,69 @@ impl Color {
         }
     }
 
+    /// Convert to an 8-digit hex string including alpha, e.g. `#7C3AED66`.
+    ///
+    /// The `Rgb` variant writes alpha `FF`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn hex_rgba(&self) -> String {
+        let rgba = self.to_rgba();
+        format!("#{:02X}{:02X}{:02X}{:02X}", rgba.r, rgba.g, rgba.b, rgba.a)
+    }
+
+    /// Format as a Rust integer literal for generated code, e.g.
+    /// `0xF97316`.
+    ///
+    /// The `Rgba` variant writes eight digits (`0xRRGGBBAA`). The value is
+    /// the same as `u32::from(color)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// assert_eq!(Color::rgb(249, 115, 22).to_hex_literal(), "0xF97316");
+    /// assert_eq!(Color::rgba(124, 58, 237, 102).to_hex_literal(), "0x7C3AED66");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_hex_literal(&self) -> String {
+        match self {
+            Self::Rgb(_) => format!("0x{:06X}", u32::from(*self)),
+            Self::Rgba(_) => format!("0x{:08X}", u32::from(*self)),
+        }
+    }
+
+    /// Convert to a lowercase hex string, e.g. `#f97316`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn hex_lower(&self) -> String {
+        self.hex_with_case(HexCase::Lower)
+    }
+
+    /// Convert to a hex string (alpha dropped) with the given digit case.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn hex_with_case(&self, case: HexCase) -> String {
+        let rgb = self.to_rgb();
+        match case {
+            HexCase::Upper => rgb.hex(),
+            HexCase::Lower => format!("#{:02x}{:02x}{:02x}", rgb.r, rgb.g, rgb.b),
+        }
+    }
+
+    /// Write the hex form (with # prefix, alpha dropped) to any
+    /// [`fmt::Write`] sink.
+    ///
+    /// This is the allocation-free alternative to [`Color::hex`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn write_hex<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        self.to_rgb().write_hex(out)
+    }
+
     /// Get the RGB components.
     #[must_use]
     pub const fn to_rgb(&self) -> Rgb {
@@ -202,6 +330,66 @@ impl Color {
         }
     }
 
+    /// Red channel.
+    #[must_use]
+    pub const fn r(&self) -> u8 {
+        self.to_rgba().r
+    }
+
+    /// Green channel.
+    #[must_use]
+    pub const fn g(&self) -> u8 {
+        self.to_rgba().g
+    }
+
+    /// Blue channel.
+    #[must_use]
+    pub const fn b(&self) -> u8 {
+        self.to_rgba().b
+    }
+
+    /// Alpha channel (255 for the `Rgb` variant).
+    #[must_use]
+    pub const fn a(&self) -> u8 {
+        self.to_rgba().a
+    }
+
+    /// Integer approximation of brightness (0 - 255), ignoring alpha.
+    ///
+    /// A Rec. 709 weighted sum of the gamma-encoded channels. Unlike
+    /// [`luminance`](Self::luminance) it is a `const fn`, so it can be used in
+    /// compile-time checks, but it is only suitable for ordering colors.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn luminance_approx(&self) -> u8 {
+        let rgb = self.to_rgb();
+        let sum = 2126 * rgb.r as u32 + 7152 * rgb.g as u32 + 722 * rgb.b as u32;
+        ((sum + 5000) / 10000) as u8
+    }
+
+    /// Whether the red, green and blue channels are all within `tolerance`
+    /// of each other. Alpha is ignored.
+    ///
+    /// The brand neutrals carry a slight blue tint (`neutral::SCALE_500` is
+    /// `#71717A`), so a tolerance around 10 treats them as gray.
+    #[must_use]
+    pub const fn is_grayscale(&self, tolerance: u8) -> bool {
+        let Rgb { r, g, b } = self.to_rgb();
+        r.abs_diff(g) <= tolerance && g.abs_diff(b) <= tolerance && r.abs_diff(b) <= tolerance
+    }
+
+    /// The `Rgb` variant of this color, dropping any alpha.
+    #[must_use]
+    pub const fn ensure_opaque(&self) -> Self {
+        Self::Rgb(self.to_rgb())
+    }
+
+    /// The `Rgba` variant of this color (alpha 255 for `Rgb`).
+    #[must_use]
+    pub const fn ensure_alpha(&self) -> Self {
+        Self::Rgba(self.to_rgba())
+    }
+
     /// Create a new color with modified alpha.
     #[must_use]
     pub fn with_alpha(self, alpha: f32) -> Self {
@@ -209,6 +397,215 @@ impl Color {
         Self::Rgba(Rgba::from_rgb_alpha(rgb, alpha))
     }
 
+    /// Create a new color at `percent` opacity (0 - 100, clamped).
+    ///
+    /// Replaces any existing alpha, so `at_opacity(20)` gives alpha 51.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn at_opacity(self, percent: u8) -> Self {
+        let percent = if percent > 100 { 100 } else { percent as u16 };
+        let rgb = self.to_rgb();
+        Self::rgba(rgb.r, rgb.g, rgb.b, ((percent * 255 + 50) / 100) as u8)
+    }
+
+    /// A drop-shadow color tinted by this color, for an `elevation` of 0 - 5
+    /// (clamped).
+    ///
+    /// The hue is kept but the color is mixed 85% towards black, so shadows
+    /// read as near-black with a hint of the brand, like
+    /// `lair::goblin::SHADOW`. Opacity follows a fixed ramp:
+    ///
+    /// | elevation | 0 | 1 | 2 | 3 | 4 | 5 |
+    /// |-----------|---|---|---|---|---|---|
+    /// | opacity   | 0% | 10% | 15% | 20% | 25% | 30% |
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::products::lair;
+    ///
+    /// let shadow = lair::PRIMARY.shadow(3);
+    /// assert_eq!(shadow.a(), 51); // 20%
+    /// assert!(shadow.r() < 40 && shadow.g() < 40 && shadow.b() < 40);
+    /// ```
+    #[must_use]
+    pub fn shadow(&self, elevation: u8) -> Self {
+        const OPACITY: [u8; 6] = [0, 10, 15, 20, 25, 30];
+        self.ensure_opaque()
+            .mix_pct(&Self::BLACK, 85)
+            .at_opacity(OPACITY[elevation.min(5) as usize])
+    }
+
+    /// Reduce each RGB channel to `bits_per_channel` bits, as on a limited
+    /// display (5 bits for RGB565, 4 for RGB444).
+    ///
+    /// Each channel snaps to the nearest of the `2^bits` evenly spaced levels
+    /// between 0 and 255, so full white and black survive. 8 or more bits is
+    /// the identity, and 0 bits leaves a single level: black. Alpha is kept
+    /// as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// // 4 bits: levels are multiples of 17.
+    /// assert_eq!(Color::rgb(249, 115, 22).quantize(4), Color::rgb(255, 119, 17));
+    /// ```
+    #[must_use]
+    pub const fn quantize(&self, bits_per_channel: u8) -> Self {
+        if bits_per_channel >= 8 {
+            return *self;
+        }
+        let max = (1u32 << bits_per_channel) - 1;
+        let rgb = self.to_rgb();
+        self.with_rgb(Rgb::new(
+            snap_channel(rgb.r, max),
+            snap_channel(rgb.g, max),
+            snap_channel(rgb.b, max),
+        ))
+    }
+
+    /// Shift the white balance warmer (positive) or cooler (negative).
+    ///
+    /// `kelvin_delta` is an approximate color-temperature offset: +1000
+    /// scales red up and blue down by 10%, with green following at a quarter
+    /// of that rate, roughly tracking the black-body locus. The delta is
+    /// clamped to +/-10000 and channels to 0 - 255. Alpha is preserved.
+    #[must_use]
+    pub fn warm_shift(self, kelvin_delta: f32) -> Self {
+        let d = if kelvin_delta.is_nan() {
+            0.0
+        } else {
+            kelvin_delta.clamp(-10_000.0, 10_000.0) / 10_000.0
+        };
+        let rgb = self.to_rgb();
+        let scale = |c: u8, gain: f32| to_byte(to_unit(c) * gain);
+        self.with_rgb(Rgb::new(
+            scale(rgb.r, 1.0 + d),
+            scale(rgb.g, 1.0 + d / 4.0),
+            scale(rgb.b, 1.0 - d),
+        ))
+    }
+
+    /// Linearly interpolate towards `other` by `t` (0.0 - 1.0, clamped).
+    ///
+    /// Channels are interpolated in sRGB. The result is RGB when both inputs
+    /// are RGB, otherwise RGBA with the alpha interpolated as well. The
+    /// endpoints return the inputs unchanged. This is the same as
+    /// [`Color::mix_srgb`]; see [`Color::mix_linear`] for gamma-correct
+    /// blending.
+    #[must_use]
+    pub fn mix(&self, other: &Color, t: f32) -> Self {
+        if t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        let from = self.to_rgba();
+        let to = other.to_rgba();
+        let lerp = |x: u8, y: u8| to_byte(to_unit(x) + (to_unit(y) - to_unit(x)) * t);
+        let (r, g, b) = (lerp(from.r, to.r), lerp(from.g, to.g), lerp(from.b, to.b));
+        match (self, other) {
+            (Self::Rgb(_), Self::Rgb(_)) => Self::rgb(r, g, b),
+            _ => Self::rgba(r, g, b, lerp(from.a, to.a)),
+        }
+    }
+
+    /// Interpolate towards `other` by an integer `percent` (0 - 100,
+    /// clamped).
+    ///
+    /// Equivalent to [`Color::mix`] with `t = percent / 100`, but computed
+    /// in integer arithmetic so the result is exact and config files can use
+    /// CSS-style percentages. `percent` is the share of `other`, so 0
+    /// returns `self` and 100 returns `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// assert_eq!(Color::WHITE.mix_pct(&Color::BLACK, 50), Color::rgb(128, 128, 128));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn mix_pct(&self, other: &Color, percent: u8) -> Self {
+        let p = u16::from(percent.min(100));
+        if p == 0 {
+            return *self;
+        }
+        if p == 100 {
+            return *other;
+        }
+        let (from, to) = (self.to_rgba(), other.to_rgba());
+        let lerp = |x: u8, y: u8| ((u16::from(x) * (100 - p) + u16::from(y) * p + 50) / 100) as u8;
+        let (r, g, b) = (lerp(from.r, to.r), lerp(from.g, to.g), lerp(from.b, to.b));
+        match (self, other) {
+            (Self::Rgb(_), Self::Rgb(_)) => Self::rgb(r, g, b),
+            _ => Self::rgba(r, g, b, lerp(from.a, to.a)),
+        }
+    }
+
+    /// Interpolate towards `other` per sRGB channel.
+    ///
+    /// Fast, available without `std`, and matches the CSS default for
+    /// gradients and `color-mix()`, so use it when the result must agree
+    /// with a browser. Midpoints come out darker than they look: black and
+    /// white mix to `#808080`. Identical to [`Color::mix`].
+    #[must_use]
+    pub fn mix_srgb(&self, other: &Color, t: f32) -> Self {
+        self.mix(other, t)
+    }
+
+    /// Composite this color over `under` (Porter-Duff source-over).
+    ///
+    /// Unlike [`Color::mix`], this treats alpha as coverage: the result
+    /// alpha is `a_s + a_u * (1 - a_s)` and the channels are blended
+    /// premultiplied. The result is RGB when it is fully opaque, otherwise
+    /// RGBA; two fully transparent colors give [`Color::TRANSPARENT`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::colors::surface;
+    /// use molten_brand::products::lair;
+    ///
+    /// // A glow over an opaque surface is opaque.
+    /// let glow = lair::goblin::GLOW.over(&surface::BASE);
+    /// assert_eq!(glow.a(), 255);
+    /// ```
+    #[must_use]
+    pub fn over(&self, under: &Color) -> Self {
+        let src = self.to_rgba();
+        let dst = under.to_rgba();
+        let (src_a, dst_a) = (to_unit(src.a), to_unit(dst.a));
+        let out_a = src_a + dst_a * (1.0 - src_a);
+        if out_a <= 0.0 {
+            return Self::TRANSPARENT;
+        }
+        let channel = |s: u8, d: u8| {
+            to_byte((to_unit(s) * src_a + to_unit(d) * dst_a * (1.0 - src_a)) / out_a)
+        };
+        let (r, g, b) = (
+            channel(src.r, dst.r),
+            channel(src.g, dst.g),
+            channel(src.b, dst.b),
+        );
+        match to_byte(out_a) {
+            u8::MAX => Self::rgb(r, g, b),
+            a => Self::rgba(r, g, b, a),
+        }
+    }
+
+    /// Interpolate towards `other` with `t` shaped by an easing curve.
+    ///
+    /// Equivalent to `self.mix(other, ease(t, easing))`.
+    #[must_use]
+    pub fn mix_eased(&self, other: &Color, t: f32, easing: Easing) -> Self {
+        self.mix(other, ease(t, easing))
+    }
+
     /// Transparent color.
     pub const TRANSPARENT: Self = Self::rgba(0, 0, 0, 0);
 
@@ -228,39 +625,2608 @@ impl fmt::Display for Color {
     }
 }
 
-impl From<Rgb> for Color {
-    fn from(rgb: Rgb) -> Self {
-        Self::Rgb(rgb)
+/// Letter case for hex digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexCase {
+    /// `#F97316`, as produced by [`Color::hex`].
+    #[default]
+    Upper,
+    /// `#f97316`, as used by the TypeScript tokens.
+    Lower,
+}
+
+/// An error returned when parsing a color from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The hex string has an unsupported number of digits.
+    InvalidLength(usize),
+    /// The hex string contains a non-hexadecimal character.
+    InvalidDigit,
+    /// A functional notation channel is missing, malformed, or out of range.
+    InvalidChannel,
+    /// The string is not a recognized color format.
+    UnknownFormat,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => {
+                write!(f, "invalid hex length {len} (expected 3, 4, 6 or 8)")
+            }
+            Self::InvalidDigit => write!(f, "invalid hex digit"),
+            Self::InvalidChannel => write!(f, "invalid color channel"),
+            Self::UnknownFormat => write!(f, "unrecognized color format"),
+        }
     }
 }
 
-impl From<Rgba> for Color {
-    fn from(rgba: Rgba) -> Self {
-        Self::Rgba(rgba)
+#[cfg(feature = "std")]
+impl std::error::Error for ParseColorError {}
+
+impl Color {
+    /// Parse a hex color (`#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`, `#` optional).
+    ///
+    /// Six-digit input produces an RGB color; input carrying alpha produces RGBA.
+    /// Parsing works on raw bytes and never panics: whitespace, non-ASCII
+    /// characters and a second `#` are all rejected as invalid digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseColorError`] if the string is not valid hex.
+    pub fn try_from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex).as_bytes();
+        let mut nibbles = [0_u8; 8];
+        for (i, &byte) in digits.iter().enumerate() {
+            let nibble = hex_nibble(byte).ok_or(ParseColorError::InvalidDigit)?;
+            if let Some(slot) = nibbles.get_mut(i) {
+                *slot = nibble;
+            }
+        }
+        let n = nibbles;
+        match digits.len() {
+            3 => Ok(Self::rgb(n[0] * 17, n[1] * 17, n[2] * 17)),
+            4 => Ok(Self::rgba(n[0] * 17, n[1] * 17, n[2] * 17, n[3] * 17)),
+            6 => Ok(Self::rgb(
+                n[0] << 4 | n[1],
+                n[2] << 4 | n[3],
+                n[4] << 4 | n[5],
+            )),
+            8 => Ok(Self::rgba(
+                n[0] << 4 | n[1],
+                n[2] << 4 | n[3],
+                n[4] << 4 | n[5],
+                n[6] << 4 | n[7],
+            )),
+            len => Err(ParseColorError::InvalidLength(len)),
+        }
+    }
+
+    /// Parse a CSS color: hex, `rgb()` or `rgba()`.
+    ///
+    /// Both the legacy comma form (`rgba(124, 58, 237, 0.3)`) and the CSS
+    /// Color 4 space form with an optional slash alpha
+    /// (`rgb(124 58 237 / 30%)`) are accepted; `rgb` and `rgba` are aliases.
+    /// Alpha may be a 0.0 - 1.0 number or a percentage. This accepts the
+    /// output of the [`Display`](fmt::Display) impl.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseColorError`] if the string is not a supported color.
+    pub fn from_css(css: &str) -> Result<Self, ParseColorError> {
+        let css = css.trim();
+        if let Some(args) = function_args(css, "rgba").or_else(|| function_args(css, "rgb")) {
+            let ([r, g, b], alpha) = split_rgb_args(args)?;
+            let (r, g, b) = (channel(r)?, channel(g)?, channel(b)?);
+            match alpha {
+                Some(alpha) => Ok(Self::rgba(r, g, b, alpha_channel(alpha)?)),
+                None => Ok(Self::rgb(r, g, b)),
+            }
+        } else if css.starts_with('#') || css.bytes().all(|b| b.is_ascii_hexdigit()) {
+            Self::try_from_hex(css)
+        } else {
+            Err(ParseColorError::UnknownFormat)
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// The value of an ASCII hex digit.
+const fn hex_nibble(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
 
-    #[test]
-    fn test_rgb_from_hex() {
-        let color = Rgb::from_hex("#F97316");
-        assert_eq!(color.r, 249);
-        assert_eq!(color.g, 115);
-        assert_eq!(color.b, 22);
+/// Return the text between `name(` and `)`, if `css` is that function call.
+fn function_args<'a>(css: &'a str, name: &str) -> Option<&'a str> {
+    let rest = css
+        .get(..name.len())
+        .filter(|head| head.eq_ignore_ascii_case(name))?;
+    css[rest.len()..]
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+/// Split `rgb()` arguments into three channels and an optional alpha.
+///
+/// Handles `r, g, b[, a]`, `r g b` and either form followed by `/ a`.
+fn split_rgb_args(args: &str) -> Result<([&str; 3], Option<&str>), ParseColorError> {
+    let (channels, slash_alpha) = match args.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (args, None),
+    };
+    let comma_separated = channels.contains(',');
+    let (parts, count) = if comma_separated {
+        collect_args(channels.split(',').map(str::trim))?
+    } else {
+        collect_args(channels.split_whitespace())?
+    };
+    match (count, slash_alpha) {
+        (3, alpha) => Ok(([parts[0], parts[1], parts[2]], alpha)),
+        (4, None) if comma_separated => Ok(([parts[0], parts[1], parts[2]], Some(parts[3]))),
+        _ => Err(ParseColorError::InvalidChannel),
     }
+}
 
-    #[test]
-    fn test_rgb_to_hex() {
-        let color = Rgb::new(249, 115, 22);
-        assert_eq!(color.hex(), "#F97316");
+/// Collect up to four arguments, returning them with their count.
+fn collect_args<'a>(
+    pieces: impl Iterator<Item = &'a str>,
+) -> Result<([&'a str; 4], usize), ParseColorError> {
+    let mut parts = [""; 4];
+    let mut count = 0;
+    for piece in pieces {
+        *parts
+            .get_mut(count)
+            .ok_or(ParseColorError::InvalidChannel)? = piece;
+        count += 1;
     }
+    Ok((parts, count))
+}
 
-    #[test]
-    fn test_rgba_alpha() {
-        let rgba = Rgba::from_rgb_alpha(Rgb::new(124, 58, 237), 0.4);
-        assert_eq!(rgba.a, 102); // 0.4 * 255 ≈ 102
+/// Parse an alpha value given as a 0.0 - 1.0 number or a percentage.
+fn alpha_channel(value: &str) -> Result<u8, ParseColorError> {
+    let (number, scale) = match value.strip_suffix('%') {
+        Some(number) => (number.trim_end(), 100.0),
+        None => (value, 1.0),
+    };
+    let alpha = number
+        .parse::<f32>()
+        .map_err(|_| ParseColorError::InvalidChannel)?
+        / scale;
+    if (0.0..=1.0).contains(&alpha) {
+        Ok(to_byte(alpha))
+    } else {
+        Err(ParseColorError::InvalidChannel)
+    }
+}
+
+/// Parse a single 0 - 255 channel.
+fn channel(value: &str) -> Result<u8, ParseColorError> {
+    value.parse().map_err(|_| ParseColorError::InvalidChannel)
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parse any format accepted by [`Color::from_css`].
+    ///
+    /// `color.to_string().parse::<Color>()` round-trips for every color,
+    /// including every alpha byte.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_css(s)
+    }
+}
+
+/// Blend modes for combining a layer with the color beneath it.
+///
+/// These follow the separable blend modes from the CSS compositing spec and
+/// operate on the RGB channels only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendMode {
+    /// The layer replaces the backdrop.
+    #[default]
+    Normal,
+    /// Multiplies channels, always darkening.
+    Multiply,
+    /// Inverse of multiply, always lightening.
+    Screen,
+    /// Multiplies or screens depending on the backdrop.
+    Overlay,
+    /// Keeps the darker of the two channels.
+    Darken,
+    /// Keeps the lighter of the two channels.
+    Lighten,
+}
+
+impl BlendMode {
+    /// Blend a single normalized channel of `backdrop` with `source`.
+    fn apply(self, backdrop: f32, source: f32) -> f32 {
+        match self {
+            Self::Normal => source,
+            Self::Multiply => backdrop * source,
+            Self::Screen => backdrop + source - backdrop * source,
+            Self::Overlay => {
+                if backdrop <= 0.5 {
+                    2.0 * backdrop * source
+                } else {
+                    1.0 - 2.0 * (1.0 - backdrop) * (1.0 - source)
+                }
+            }
+            Self::Darken => backdrop.min(source),
+            Self::Lighten => backdrop.max(source),
+        }
+    }
+}
+
+impl Color {
+    /// Blend `layer` on top of this color using the given blend mode.
+    ///
+    /// The backdrop's alpha is preserved.
+    #[must_use]
+    pub fn blend(&self, layer: &Color, mode: BlendMode) -> Self {
+        let base = self.to_rgb();
+        let top = layer.to_rgb();
+        let channel = |b: u8, s: u8| to_byte(mode.apply(to_unit(b), to_unit(s)));
+        self.with_rgb(Rgb::new(
+            channel(base.r, top.r),
+            channel(base.g, top.g),
+            channel(base.b, top.b),
+        ))
+    }
+
+    /// Screen `layers` onto black in order, as stacked glows accumulate.
+    ///
+    /// Each layer brightens the result by `1 - (1 - backdrop) * (1 - layer)`
+    /// per channel, scaled by the layer's alpha, so a 40% glow adds 40% of
+    /// its full effect. Screening is commutative, so the order does not
+    /// matter. Channels are accumulated without intermediate rounding, and
+    /// the result is opaque; an empty stack is black.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// let half = Color::rgb(128, 0, 0);
+    /// assert_eq!(Color::screen_stack(&[half, half]), Color::rgb(192, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn screen_stack(layers: &[Color]) -> Self {
+        let mut acc = [0.0f32; 3];
+        for layer in layers {
+            let rgba = layer.to_rgba();
+            let alpha = to_unit(rgba.a);
+            for (backdrop, source) in acc.iter_mut().zip([rgba.r, rgba.g, rgba.b]) {
+                let screened = BlendMode::Screen.apply(*backdrop, to_unit(source));
+                *backdrop += (screened - *backdrop) * alpha;
+            }
+        }
+        Self::rgb(to_byte(acc[0]), to_byte(acc[1]), to_byte(acc[2]))
+    }
+
+    /// Replace the RGB channels, keeping this color's variant and alpha.
+    const fn with_rgb(self, rgb: Rgb) -> Self {
+        match self {
+            Self::Rgb(_) => Self::Rgb(rgb),
+            Self::Rgba(rgba) => Self::rgba(rgb.r, rgb.g, rgb.b, rgba.a),
+        }
+    }
+}
+
+/// Easing curves for animated color transitions.
+///
+/// The eased variants are cubic, matching CSS `ease-in`/`ease-out` closely
+/// enough for status and hover transitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// Starts slow, ends fast.
+    EaseIn,
+    /// Starts fast, ends slow.
+    EaseOut,
+    /// Slow at both ends.
+    EaseInOut,
+}
+
+/// Map linear progress `t` (0.0 - 1.0, clamped) through an easing curve.
+///
+/// Every curve maps 0.0 to 0.0 and 1.0 to 1.0.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::color::{ease, Easing};
+///
+/// assert_eq!(ease(0.5, Easing::Linear), 0.5);
+/// assert!(ease(0.25, Easing::EaseIn) < 0.25);
+/// ```
+#[must_use]
+pub fn ease(t: f32, easing: Easing) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    match easing {
+        Easing::Linear => t,
+        Easing::EaseIn => t * t * t,
+        Easing::EaseOut => {
+            let inv = 1.0 - t;
+            1.0 - inv * inv * inv
+        }
+        Easing::EaseInOut => {
+            if t < 0.5 {
+                4.0 * t * t * t
+            } else {
+                let inv = 2.0 - 2.0 * t;
+                1.0 - inv * inv * inv / 2.0
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Color {
+    /// Convert to HSL: hue in degrees (0 - 360), saturation and lightness
+    /// as 0.0 - 1.0. Alpha is ignored.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let rgb = self.to_rgb();
+        let max_channel = rgb.r.max(rgb.g).max(rgb.b);
+        let min_channel = rgb.r.min(rgb.g).min(rgb.b);
+        let (r, g, b) = rgb.to_f32();
+        let (max, min) = (to_unit(max_channel), to_unit(min_channel));
+        let l = (max + min) / 2.0;
+        if max_channel == min_channel {
+            return (0.0, 0.0, l);
+        }
+        let d = max - min;
+        let s = d / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max_channel == rgb.r {
+            ((g - b) / d).rem_euclid(6.0)
+        } else if max_channel == rgb.g {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        (h * 60.0, s.clamp(0.0, 1.0), l)
+    }
+
+    /// Create an opaque color from HSL components.
+    ///
+    /// The hue wraps around 360 degrees; saturation and lightness are clamped.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match h {
+            h if h < 1.0 => (c, x, 0.0),
+            h if h < 2.0 => (x, c, 0.0),
+            h if h < 3.0 => (0.0, c, x),
+            h if h < 4.0 => (0.0, x, c),
+            h if h < 5.0 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = l - c / 2.0;
+        Self::rgb(to_byte(r + m), to_byte(g + m), to_byte(b + m))
+    }
+
+    /// A stable, on-brand color for an arbitrary label such as an agent or
+    /// branch name.
+    ///
+    /// The label is hashed with 64-bit FNV-1a, so the same label gives the
+    /// same color on every run and platform. The hash picks any hue, a
+    /// saturation of 0.65 - 0.85 and a lightness of 0.50 - 0.65, keeping
+    /// every result vivid and readable on the dark surfaces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// assert_eq!(Color::from_label("goblin-1"), Color::from_label("goblin-1"));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_label(label: &str) -> Self {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        let hash = label.bytes().fold(FNV_OFFSET, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        });
+        let hue = (hash % 360) as f32;
+        let saturation = 0.65 + ((hash >> 16) % 21) as f32 / 100.0;
+        let lightness = 0.5 + ((hash >> 32) % 16) as f32 / 100.0;
+        Self::from_hsl(hue, saturation, lightness)
+    }
+
+    /// Rotate the hue by the given number of degrees, preserving alpha.
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        self.with_rgb(Self::from_hsl(h + degrees, s, l).to_rgb())
+    }
+
+    /// Increase HSL lightness by `amount` (0.0 - 1.0), preserving alpha.
+    #[must_use]
+    pub fn lighten(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        self.with_rgb(Self::from_hsl(h, s, l + amount).to_rgb())
+    }
+
+    /// Decrease HSL lightness by `amount` (0.0 - 1.0), preserving alpha.
+    #[must_use]
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Set the HSL hue to `h` degrees (clamped to 0 - 360), preserving
+    /// saturation, lightness and alpha.
+    #[must_use]
+    pub fn with_hue(self, h: f32) -> Self {
+        let (_, s, l) = self.to_hsl();
+        self.with_rgb(Self::from_hsl(h.clamp(0.0, 360.0), s, l).to_rgb())
+    }
+
+    /// Set the HSL saturation to `s` (clamped to 0.0 - 1.0), preserving hue,
+    /// lightness and alpha.
+    #[must_use]
+    pub fn with_saturation(self, s: f32) -> Self {
+        let (h, _, l) = self.to_hsl();
+        self.with_rgb(Self::from_hsl(h, s, l).to_rgb())
+    }
+
+    /// Set the HSL lightness to `l` (clamped to 0.0 - 1.0), preserving hue,
+    /// saturation and alpha.
+    #[must_use]
+    pub fn with_lightness(self, l: f32) -> Self {
+        let (h, s, _) = self.to_hsl();
+        self.with_rgb(Self::from_hsl(h, s, l).to_rgb())
+    }
+
+    /// Shift HSL lightness by the smallest amount that reaches `min_ratio`
+    /// contrast against `background`, preserving hue, saturation and alpha.
+    ///
+    /// A color that already passes is returned unchanged. Otherwise it is
+    /// lightened on dark backgrounds (luminance below 0.18) and darkened on
+    /// light ones, bisecting the lightness to within 0.0001. If even full
+    /// lightness (or darkness) cannot reach `min_ratio`, that extreme is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::colors::surface;
+    /// use molten_brand::products::lair;
+    ///
+    /// let accent = lair::ACCENT.adjust_for_contrast(&surface::BASE, 4.5);
+    /// assert!(accent.contrast_ratio(&surface::BASE) >= 4.5);
+    /// ```
+    #[must_use]
+    pub fn adjust_for_contrast(&self, background: &Color, min_ratio: f32) -> Self {
+        if self.contrast_ratio(background) >= min_ratio {
+            return *self;
+        }
+        let (h, s, l) = self.to_hsl();
+        let at = |l: f32| self.with_rgb(Self::from_hsl(h, s, l).to_rgb());
+        let lighten = background.luminance() < 0.18;
+        // `pass` always holds the lightness nearest the original that passes.
+        let (mut fail, mut pass) = if lighten { (l, 1.0) } else { (l, 0.0) };
+        for _ in 0..14 {
+            let mid = (fail + pass) / 2.0;
+            if at(mid).contrast_ratio(background) >= min_ratio {
+                pass = mid;
+            } else {
+                fail = mid;
+            }
+        }
+        at(pass)
+    }
+
+    /// A focus outline color derived from this color.
+    ///
+    /// Follows the translucent `BORDER_HOVER` pattern used by the product
+    /// surfaces, but stronger so the ring stays visible: the color is
+    /// lightened by 0.1 HSL lightness and given 50% opacity (alpha 128).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::products::lair;
+    ///
+    /// let ring = lair::PRIMARY.focus_ring();
+    /// assert_eq!(ring.a(), 128);
+    /// ```
+    #[must_use]
+    pub fn focus_ring(&self) -> Self {
+        const LIGHTEN: f32 = 0.1;
+        const ALPHA: u8 = 128;
+        let rgb = self.lighten(LIGHTEN).to_rgb();
+        Self::rgba(rgb.r, rgb.g, rgb.b, ALPHA)
+    }
+
+    /// A subtle, opaque border for this foreground color on `surface`.
+    ///
+    /// The border is the surface mixed 20% towards the foreground, which
+    /// looks like the foreground at 20% opacity over the surface. If that is
+    /// within a Lab distance of 8 of the surface (the foreground is too close
+    /// to it), the surface is mixed 20% towards black or white instead,
+    /// whichever contrasts with it. Alpha on either input is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::colors::{surface, text};
+    ///
+    /// let border = text::PRIMARY.border_on(&surface::RAISED);
+    /// assert!(border.distance(&surface::RAISED) >= 8.0);
+    /// ```
+    #[must_use]
+    pub fn border_on(&self, surface: &Color) -> Self {
+        const OPACITY: f32 = 0.2;
+        const MIN_DISTANCE: f32 = 8.0;
+        let surface = Self::Rgb(surface.to_rgb());
+        let border = surface.mix(&Self::Rgb(self.to_rgb()), OPACITY);
+        if border.distance(&surface) >= MIN_DISTANCE {
+            return border;
+        }
+        let contrast = if surface.luminance() > 0.18 {
+            Self::BLACK
+        } else {
+            Self::WHITE
+        };
+        surface.mix(&contrast, OPACITY)
+    }
+
+    /// Convert to CIE L*a*b* (D65 white point). Alpha is ignored.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn to_lab(&self) -> (f32, f32, f32) {
+        let rgb = self.to_rgb();
+        let (r, g, b) = (linearize(rgb.r), linearize(rgb.g), linearize(rgb.b));
+        let x = (0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b) / 0.950_47;
+        let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b;
+        let z = (0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b) / 1.088_83;
+        let f = |t: f32| {
+            if t > 0.008_856 {
+                t.cbrt()
+            } else {
+                7.787 * t + 16.0 / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+
+    /// Quantize into a coarse L*a*b* bucket id for grouping similar colors.
+    ///
+    /// Each of L* (0 - 100), a* and b* (-128 - 128) is split into `buckets`
+    /// equal ranges (at least 1), giving up to `buckets³` ids. Unlike the
+    /// exact `Hash` impl, visually similar colors usually share an id,
+    /// though two colors straddling a boundary can still differ. Alpha is
+    /// ignored.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn perceptual_bucket(&self, buckets: u8) -> u32 {
+        let n = u32::from(buckets.max(1));
+        let quantize = |value: f32, min: f32, max: f32| {
+            let unit = ((value - min) / (max - min)).clamp(0.0, 1.0);
+            ((unit * n as f32) as u32).min(n - 1)
+        };
+        let (l, a, b) = self.to_lab();
+        let (l, a, b) = (
+            quantize(l, 0.0, 100.0),
+            quantize(a, -128.0, 128.0),
+            quantize(b, -128.0, 128.0),
+        );
+        (l * n + a) * n + b
+    }
+
+    /// Whether two colors fall in the same [`perceptual_bucket`](Color::perceptual_bucket).
+    #[must_use]
+    pub fn same_bucket(&self, other: &Color, buckets: u8) -> bool {
+        self.perceptual_bucket(buckets) == other.perceptual_bucket(buckets)
+    }
+
+    /// Perceptual distance to another color (CIE76 delta E in L*a*b*).
+    ///
+    /// A distance below roughly 2.3 is imperceptible to most viewers.
+    #[must_use]
+    pub fn distance(&self, other: &Color) -> f32 {
+        let (l1, a1, b1) = self.to_lab();
+        let (l2, a2, b2) = other.to_lab();
+        ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+    }
+
+    /// A detailed comparison from `self` to `other`.
+    ///
+    /// Each field is `other` minus `self`; see [`ColorDelta`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// let delta = Color::rgb(249, 115, 22).delta(&Color::rgb(234, 88, 12));
+    /// assert_eq!((delta.r, delta.g, delta.b), (-15, -27, -10));
+    /// ```
+    #[must_use]
+    pub fn delta(&self, other: &Color) -> ColorDelta {
+        let (a, b) = (self.to_rgba(), other.to_rgba());
+        let (h1, s1, l1) = self.to_hsl();
+        let (h2, s2, l2) = other.to_hsl();
+        let hue = (h2 - h1 + 180.0).rem_euclid(360.0) - 180.0;
+        ColorDelta {
+            r: i16::from(b.r) - i16::from(a.r),
+            g: i16::from(b.g) - i16::from(a.g),
+            b: i16::from(b.b) - i16::from(a.b),
+            a: i16::from(b.a) - i16::from(a.a),
+            hue,
+            saturation: s2 - s1,
+            lightness: l2 - l1,
+            distance: self.distance(other),
+        }
+    }
+
+    /// Blend several colors by weight in linear light.
+    ///
+    /// Weights are normalized, so only their ratios matter. Negative weights
+    /// are clamped to zero. Returns `None` for an empty slice or when every
+    /// weight is zero. The result is RGB when every input is RGB, otherwise
+    /// RGBA with the alpha blended by the same weights.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// let busy = Color::mix_many(&[(Color::BLACK, 0.25), (Color::WHITE, 0.75)]);
+    /// assert!(busy.is_some_and(|c| c.r() > 200));
+    /// ```
+    #[must_use]
+    pub fn mix_many(colors: &[(Color, f32)]) -> Option<Self> {
+        let total: f32 = colors.iter().map(|(_, w)| w.max(0.0)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut sum = [0.0_f32; 4];
+        for (color, weight) in colors {
+            let weight = weight.max(0.0) / total;
+            let rgba = color.to_rgba();
+            sum[0] += linearize(rgba.r) * weight;
+            sum[1] += linearize(rgba.g) * weight;
+            sum[2] += linearize(rgba.b) * weight;
+            sum[3] += to_unit(rgba.a) * weight;
+        }
+        let [r, g, b] = [sum[0], sum[1], sum[2]].map(|c| to_byte(delinearize(c)));
+        if colors
+            .iter()
+            .all(|(color, _)| matches!(color, Self::Rgb(_)))
+        {
+            Some(Self::rgb(r, g, b))
+        } else {
+            Some(Self::rgba(r, g, b, to_byte(sum[3])))
+        }
+    }
+
+    /// Interpolate towards `other` in linear light (gamma-correct).
+    ///
+    /// Physically correct blending, as when two lights overlap, which keeps
+    /// midpoints from muddying; use it for generated ramps and anything the
+    /// eye judges directly. Black and white mix to `#BCBCBC`. `t` is clamped
+    /// to 0.0 - 1.0 and the endpoints return the inputs unchanged. Alpha is
+    /// interpolated linearly, as in [`Color::mix`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// assert_eq!(Color::BLACK.mix_srgb(&Color::WHITE, 0.5).r(), 128);
+    /// assert_eq!(Color::BLACK.mix_linear(&Color::WHITE, 0.5).r(), 188);
+    /// ```
+    #[must_use]
+    pub fn mix_linear(&self, other: &Color, t: f32) -> Self {
+        if t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        Self::mix_many(&[(*self, 1.0 - t), (*other, t)]).unwrap_or(*self)
+    }
+
+    /// Interpolate towards `other` in HSL, taking the shorter way around the
+    /// hue circle.
+    ///
+    /// Hue 350 and hue 10 meet at red rather than passing through cyan, and
+    /// distant hues stay saturated where [`Color::mix`] would go gray. A
+    /// gray endpoint has no hue, so the other endpoint's hue is used
+    /// throughout. `t` is clamped to 0.0 - 1.0 and alpha is interpolated as
+    /// in [`Color::mix`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Color;
+    ///
+    /// let red = Color::rgb(255, 0, 0);
+    /// let blue = Color::rgb(0, 0, 255);
+    /// assert_eq!(red.mix_hsl(&blue, 0.5), Color::rgb(255, 0, 255));
+    /// ```
+    #[must_use]
+    pub fn mix_hsl(&self, other: &Color, t: f32) -> Self {
+        if t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        let (h1, s1, l1) = self.to_hsl();
+        let (h2, s2, l2) = other.to_hsl();
+        let (h1, h2) = match (s1 > 0.0, s2 > 0.0) {
+            (false, true) => (h2, h2),
+            (true, false) => (h1, h1),
+            _ => (h1, h2),
+        };
+        let hue = h1 + ((h2 - h1 + 180.0).rem_euclid(360.0) - 180.0) * t;
+        let mixed = Self::from_hsl(hue, s1 + (s2 - s1) * t, l1 + (l2 - l1) * t).to_rgb();
+        if let (Self::Rgb(_), Self::Rgb(_)) = (self, other) {
+            return Self::Rgb(mixed);
+        }
+        let (a1, a2) = (to_unit(self.a()), to_unit(other.a()));
+        Self::rgba(mixed.r, mixed.g, mixed.b, to_byte(a1 + (a2 - a1) * t))
+    }
+
+    /// Convert to OKLCH: lightness (0.0 - 1.0), chroma (0.0 - ~0.37) and hue
+    /// in degrees (0 - 360). Alpha is ignored.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        let rgb = self.to_rgb();
+        let (r, g, b) = (linearize(rgb.r), linearize(rgb.g), linearize(rgb.b));
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        let lightness = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+        let a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+        let b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+        let chroma = a.hypot(b);
+        let hue = if chroma < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+        (lightness, chroma, hue)
+    }
+
+    /// CSS value as an sRGB fallback line followed by an `oklch()` line.
+    ///
+    /// Emit one declaration per line, in order, for the same property:
+    /// browsers without OKLCH support ignore the second declaration and keep
+    /// the first. The fallback is hex for opaque colors and `rgba()`
+    /// otherwise; the OKLCH value carries the same alpha.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::colors::molten;
+    ///
+    /// let css: Vec<String> = molten::PRIMARY
+    ///     .css_with_fallback()
+    ///     .lines()
+    ///     .map(|value| format!("color: {value};"))
+    ///     .collect();
+    /// assert_eq!(css[0], "color: #F97316;");
+    /// assert!(css[1].starts_with("color: oklch("));
+    /// ```
+    #[must_use]
+    pub fn css_with_fallback(&self) -> String {
+        let (l, c, h) = self.to_oklch();
+        let oklch = format!("{:.2}% {c:.4} {h:.2}", l * 100.0);
+        match self {
+            Self::Rgb(rgb) => format!("{}\noklch({oklch})", rgb.hex()),
+            Self::Rgba(rgba) => {
+                format!("{}\noklch({oklch} / {:.2})", rgba.css(), rgba.alpha_f32())
+            }
+        }
+    }
+
+    /// Create an opaque color from OKLCH components.
+    ///
+    /// Lightness is clamped to 0.0 - 1.0 and the hue wraps around 360
+    /// degrees. Colors outside the sRGB gamut keep their lightness and hue
+    /// and have their chroma reduced until they fit.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn from_oklch(l: f32, c: f32, h: f32) -> Self {
+        let l = l.clamp(0.0, 1.0);
+        let c = c.max(0.0);
+        let h = h.rem_euclid(360.0).to_radians();
+        let (sin, cos) = h.sin_cos();
+        if let Some(rgb) = oklab_to_srgb(l, c * cos, c * sin) {
+            return Self::rgb(to_byte(rgb.0), to_byte(rgb.1), to_byte(rgb.2));
+        }
+        // Binary search for the largest in-gamut chroma.
+        let (mut low, mut high) = (0.0, c);
+        for _ in 0..16 {
+            let mid = (low + high) / 2.0;
+            if oklab_to_srgb(l, mid * cos, mid * sin).is_some() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let (r, g, b) = oklab_to_srgb(l, low * cos, low * sin).unwrap_or((l, l, l));
+        Self::rgb(to_byte(r), to_byte(g), to_byte(b))
+    }
+
+    /// Change the hue to `hue` degrees while keeping OKLCH lightness and
+    /// chroma.
+    ///
+    /// Unlike [`Color::with_hue`], which works in HSL, the result looks as
+    /// light as the original, so contrast against the same background is
+    /// preserved. Chroma is reduced if the new hue cannot reach it in sRGB.
+    /// Alpha is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::colors::molten;
+    ///
+    /// let purple = molten::PRIMARY.recolor_to_hue(300.0);
+    /// let (l, _, _) = molten::PRIMARY.to_oklch();
+    /// assert!((purple.to_oklch().0 - l).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn recolor_to_hue(&self, hue: f32) -> Self {
+        let (l, c, _) = self.to_oklch();
+        self.with_rgb(Self::from_oklch(l, c, hue).to_rgb())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Color {
+    /// WCAG 2 relative luminance (0.0 for black, 1.0 for white).
+    #[must_use]
+    pub fn luminance(&self) -> f32 {
+        let rgb = self.to_rgb();
+        0.2126 * linearize(rgb.r) + 0.7152 * linearize(rgb.g) + 0.0722 * linearize(rgb.b)
+    }
+
+    /// WCAG 2 contrast ratio against another color (1.0 - 21.0).
+    ///
+    /// The ratio is symmetric: swapping foreground and background gives the
+    /// same value. AA requires 4.5 for body text and 3.0 for large text.
+    #[must_use]
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.luminance();
+        let b = other.luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// APCA lightness contrast (Lc) of this text color on `bg`.
+    ///
+    /// Implements the APCA 0.0.98G algorithm from the WCAG 3 draft. Unlike
+    /// [`Color::contrast_ratio`] the result depends on polarity: it is
+    /// positive for dark text on a light background and negative for light
+    /// text on a dark background. An absolute Lc of 60 is roughly the
+    /// minimum for body text, 75 preferred.
+    #[must_use]
+    pub fn apca_contrast(&self, bg: &Color) -> f32 {
+        const BLACK_THRESHOLD: f32 = 0.022;
+        const BLACK_CLAMP: f32 = 1.414;
+        const SCALE: f32 = 1.14;
+        const LOW_CLIP: f32 = 0.1;
+        const OFFSET: f32 = 0.027;
+
+        let screen_luminance = |color: &Color| {
+            let (r, g, b) = color.to_rgb().to_f32();
+            let y = 0.212_672_9 * r.powf(2.4) + 0.715_152_2 * g.powf(2.4) + 0.072_175 * b.powf(2.4);
+            if y < BLACK_THRESHOLD {
+                y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+            } else {
+                y
+            }
+        };
+        let text = screen_luminance(self);
+        let back = screen_luminance(bg);
+        if (back - text).abs() < 0.0005 {
+            return 0.0;
+        }
+
+        let contrast = if back > text {
+            let s = (back.powf(0.56) - text.powf(0.57)) * SCALE;
+            if s < LOW_CLIP {
+                0.0
+            } else {
+                s - OFFSET
+            }
+        } else {
+            let s = (back.powf(0.65) - text.powf(0.62)) * SCALE;
+            if s > -LOW_CLIP {
+                0.0
+            } else {
+                s + OFFSET
+            }
+        };
+        contrast * 100.0
+    }
+}
+
+/// Convert an sRGB channel to linear light.
+#[cfg(feature = "std")]
+fn linearize(channel: u8) -> f32 {
+    let c = to_unit(channel);
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Convert a linear-light value back to an sRGB-encoded 0.0 - 1.0 value.
+#[cfg(feature = "std")]
+fn delinearize(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Convert Oklab to sRGB-encoded channels, or `None` if out of gamut.
+#[cfg(feature = "std")]
+#[allow(clippy::many_single_char_names)]
+fn oklab_to_srgb(l: f32, a: f32, b: f32) -> Option<(f32, f32, f32)> {
+    const EPSILON: f32 = 1e-4;
+    let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    let r = 4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_;
+    let g = -1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_;
+    let b = -0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_;
+    let in_gamut = |v: f32| (-EPSILON..=1.0 + EPSILON).contains(&v);
+    (in_gamut(r) && in_gamut(g) && in_gamut(b)).then(|| {
+        (
+            delinearize(r.clamp(0.0, 1.0)),
+            delinearize(g.clamp(0.0, 1.0)),
+            delinearize(b.clamp(0.0, 1.0)),
+        )
+    })
+}
+
+/// Convert an 8-bit channel to the 0.0 - 1.0 range.
+fn to_unit(channel: u8) -> f32 {
+    f32::from(channel) / 255.0
+}
+
+/// Convert a 0.0 - 1.0 value to an 8-bit channel, rounding to nearest.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_byte(value: f32) -> u8 {
+    // `f32::round` needs std; the value is non-negative so adding 0.5
+    // before truncating rounds the same way.
+    (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+/// Snap an 8-bit channel to the nearest of `max + 1` evenly spaced levels.
+#[allow(clippy::cast_possible_truncation)]
+const fn snap_channel(channel: u8, max: u32) -> u8 {
+    if max == 0 {
+        return 0;
+    }
+    let level = (channel as u32 * max + 127) / 255;
+    ((level * 255 + max / 2) / max) as u8
+}
+
+/// The difference between two colors, as returned by [`Color::delta`].
+///
+/// Every field is the second color minus the first. Hue is the shortest
+/// signed angle in degrees (-180 - 180); saturation and lightness are HSL
+/// differences in 0.0 - 1.0 units.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorDelta {
+    /// Red channel difference.
+    pub r: i16,
+    /// Green channel difference.
+    pub g: i16,
+    /// Blue channel difference.
+    pub b: i16,
+    /// Alpha channel difference.
+    pub a: i16,
+    /// HSL hue difference in degrees.
+    pub hue: f32,
+    /// HSL saturation difference.
+    pub saturation: f32,
+    /// HSL lightness difference.
+    pub lightness: f32,
+    /// Perceptual distance (see [`Color::distance`]).
+    pub distance: f32,
+}
+
+/// A multi-stop gradient that can be sampled at any position.
+///
+/// Requires the `std` feature.
+///
+/// Stops are spaced evenly from 0.0 to 1.0 and interpolated with
+/// [`Color::mix`].
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{Color, ColorRamp};
+///
+/// const FADE: ColorRamp = ColorRamp::new(&[Color::BLACK, Color::WHITE]);
+/// assert_eq!(FADE.sample(0.5), Color::rgb(128, 128, 128));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColorRamp {
+    stops: Cow<'static, [Color]>,
+}
+
+#[cfg(feature = "std")]
+impl ColorRamp {
+    /// Create a ramp from a static list of stops.
+    #[must_use]
+    pub const fn new(stops: &'static [Color]) -> Self {
+        Self {
+            stops: Cow::Borrowed(stops),
+        }
+    }
+
+    /// Create a ramp from owned stops.
+    #[must_use]
+    pub fn from_stops(stops: Vec<Color>) -> Self {
+        Self {
+            stops: Cow::Owned(stops),
+        }
+    }
+
+    /// The gradient stops, evenly spaced from start to end.
+    #[must_use]
+    pub fn stops(&self) -> &[Color] {
+        &self.stops
+    }
+
+    /// Sample the ramp at `t` (0.0 - 1.0, clamped).
+    ///
+    /// An empty ramp samples as [`Color::TRANSPARENT`].
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn sample(&self, t: f32) -> Color {
+        match self.stops.as_ref() {
+            [] => Color::TRANSPARENT,
+            [only] => *only,
+            stops => {
+                let pos = t.clamp(0.0, 1.0) * (stops.len() - 1) as f32;
+                let index = (pos.floor() as usize).min(stops.len() - 2);
+                stops[index].mix(&stops[index + 1], pos - index as f32)
+            }
+        }
+    }
+
+    /// Sample the ramp at `t` shaped by an easing curve.
+    ///
+    /// The easing applies across the whole ramp, not per segment.
+    #[must_use]
+    pub fn sample_eased(&self, t: f32, easing: Easing) -> Color {
+        self.sample(ease(t, easing))
+    }
+
+    /// Sample `n` evenly spaced colors, including both endpoints.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn steps(&self, n: usize) -> Vec<Color> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.sample(0.0)],
+            _ => (0..n)
+                .map(|i| self.sample(i as f32 / (n - 1) as f32))
+                .collect(),
+        }
+    }
+}
+
+/// The SGR sequence that resets all terminal styling.
+pub const ANSI_RESET: &str = "\x1b[0m";
+
+/// Foreground, background and attributes for a single terminal cell.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::color::CellStyle;
+/// use molten_brand::colors::{molten, surface};
+///
+/// let style = CellStyle::new(molten::PRIMARY).with_bg(surface::BASE).bold();
+/// # #[cfg(feature = "std")]
+/// println!("{}", style.paint("🔥 cooking"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellStyle {
+    /// Foreground (text) color.
+    pub fg: Color,
+    /// Background color, or `None` to keep the terminal default.
+    pub bg: Option<Color>,
+    /// Bold text.
+    pub bold: bool,
+    /// Italic text.
+    pub italic: bool,
+    /// Underlined text.
+    pub underline: bool,
+}
+
+impl CellStyle {
+    /// Create a style with the given foreground and no attributes.
+    #[must_use]
+    pub const fn new(fg: Color) -> Self {
+        Self {
+            fg,
+            bg: None,
+            bold: false,
+            italic: false,
+            underline: false,
+        }
+    }
+
+    /// Set the background color.
+    #[must_use]
+    pub const fn with_bg(mut self, bg: Color) -> Self {
+        self.bg = Some(bg);
+        self
+    }
+
+    /// Enable bold.
+    #[must_use]
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Enable italic.
+    #[must_use]
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Enable underline.
+    #[must_use]
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Emit the combined 24-bit SGR sequence for this style.
+    ///
+    /// Terminals have no alpha channel, so translucent colors are emitted
+    /// as their opaque RGB. Use [`CellStyle::to_ansi_over`] to composite
+    /// them over a known base first.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::with_capacity(40);
+        let _ = self.write_ansi(&mut out);
+        out
+    }
+
+    /// Emit the SGR sequence with translucent colors composited over `base`.
+    ///
+    /// The background is composited over `base`, then the foreground over
+    /// the resulting background.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_ansi_over(&self, base: Color) -> String {
+        let mut out = String::with_capacity(40);
+        let _ = self.write_ansi_over(&mut out, base);
+        out
+    }
+
+    /// Wrap `text` in this style, followed by [`ANSI_RESET`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn paint(&self, text: &str) -> String {
+        format!("{}{text}{ANSI_RESET}", self.to_ansi())
+    }
+
+    /// Write the SGR sequence to any [`fmt::Write`] sink.
+    ///
+    /// This is the allocation-free alternative to [`CellStyle::to_ansi`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn write_ansi<W: fmt::Write>(&self, out: &mut W) -> fmt::Result {
+        self.write_sgr(out, self.fg.to_rgb(), self.bg.map(|bg| bg.to_rgb()))
+    }
+
+    /// Write the composited SGR sequence to any [`fmt::Write`] sink.
+    ///
+    /// This is the allocation-free alternative to [`CellStyle::to_ansi_over`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn write_ansi_over<W: fmt::Write>(&self, out: &mut W, base: Color) -> fmt::Result {
+        let bg = self.bg.map(|bg| composite(bg, base.to_rgb()));
+        let fg = composite(self.fg, bg.unwrap_or_else(|| base.to_rgb()));
+        self.write_sgr(out, fg, bg)
+    }
+
+    /// The sequence that resets styling after a styled cell.
+    #[must_use]
+    pub const fn reset() -> &'static str {
+        ANSI_RESET
+    }
+
+    fn write_sgr<W: fmt::Write>(&self, out: &mut W, fg: Rgb, bg: Option<Rgb>) -> fmt::Result {
+        out.write_str("\x1b[")?;
+        if self.bold {
+            out.write_str("1;")?;
+        }
+        if self.italic {
+            out.write_str("3;")?;
+        }
+        if self.underline {
+            out.write_str("4;")?;
+        }
+        write!(out, "38;2;{};{};{}", fg.r, fg.g, fg.b)?;
+        if let Some(bg) = bg {
+            write!(out, ";48;2;{};{};{}", bg.r, bg.g, bg.b)?;
+        }
+        out.write_str("m")
+    }
+}
+
+/// Flatten a possibly translucent color onto an opaque base.
+fn composite(color: Color, base: Rgb) -> Rgb {
+    let rgba = color.to_rgba();
+    Color::Rgb(base)
+        .mix(&Color::Rgb(rgba.to_rgb()), rgba.alpha_f32())
+        .to_rgb()
+}
+
+/// The color support of a terminal, from least to most capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TerminalCaps {
+    /// No color escapes at all (dumb terminals, `NO_COLOR`).
+    NoColor,
+    /// The 16 standard ANSI colors.
+    Ansi16,
+    /// The xterm 256-color palette.
+    Ansi256,
+    /// 24-bit RGB.
+    #[default]
+    TrueColor,
+}
+
+#[cfg(feature = "std")]
+impl TerminalCaps {
+    /// Pick capabilities from the environment.
+    ///
+    /// [`TerminalCaps::NoColor`] when `NO_COLOR` is set to a non-empty value
+    /// (see <https://no-color.org>) or `TERM` is `dumb`, otherwise
+    /// [`TerminalCaps::TrueColor`].
+    #[must_use]
+    pub fn from_env() -> Self {
+        let set = |key: &str| std::env::var_os(key).filter(|value| !value.is_empty());
+        if set("NO_COLOR").is_some() || set("TERM").is_some_and(|term| term == "dumb") {
+            Self::NoColor
+        } else {
+            Self::TrueColor
+        }
+    }
+}
+
+/// The xterm default RGB values of the 16 standard ANSI colors.
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Channel levels of the 6x6x6 color cube in the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Squared RGB distance between two colors.
+fn rgb_distance_sq(a: Rgb, b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    d(a.r, b.0) + d(a.g, b.1) + d(a.b, b.2)
+}
+
+impl Color {
+    /// The nearest index (0 - 15) in the standard 16-color ANSI palette.
+    ///
+    /// Distances are measured against the xterm default palette.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_ansi16(&self) -> u8 {
+        let rgb = self.to_rgb();
+        let mut best = (0, u32::MAX);
+        for (index, entry) in ANSI16_PALETTE.iter().enumerate() {
+            let distance = rgb_distance_sq(rgb, *entry);
+            if distance < best.1 {
+                best = (index as u8, distance);
+            }
+        }
+        best.0
+    }
+
+    /// The nearest index (16 - 255) in the xterm 256-color palette.
+    ///
+    /// Picks whichever of the 6x6x6 color cube and the 24-step grayscale
+    /// ramp is closer. The first 16 indices are skipped because terminals
+    /// commonly remap them.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_ansi256(&self) -> u8 {
+        let rgb = self.to_rgb();
+        let cube_index = |c: u8| {
+            CUBE_LEVELS
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, level)| level.abs_diff(c))
+                .map_or(0, |(i, _)| i as u8)
+        };
+        let (ri, gi, bi) = (cube_index(rgb.r), cube_index(rgb.g), cube_index(rgb.b));
+        let cube = (
+            CUBE_LEVELS[usize::from(ri)],
+            CUBE_LEVELS[usize::from(gi)],
+            CUBE_LEVELS[usize::from(bi)],
+        );
+
+        let average = (u16::from(rgb.r) + u16::from(rgb.g) + u16::from(rgb.b)) / 3;
+        let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+        let gray_level = 8 + gray_index * 10;
+
+        if rgb_distance_sq(rgb, (gray_level, gray_level, gray_level)) < rgb_distance_sq(rgb, cube) {
+            232 + gray_index
+        } else {
+            16 + 36 * ri + 6 * gi + bi
+        }
+    }
+
+    /// Write the foreground SGR sequence for the given capabilities.
+    ///
+    /// Nothing is written for [`TerminalCaps::NoColor`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn write_ansi_fg<W: fmt::Write>(&self, out: &mut W, caps: TerminalCaps) -> fmt::Result {
+        match caps {
+            TerminalCaps::NoColor => Ok(()),
+            TerminalCaps::Ansi16 => {
+                let index = self.to_ansi16();
+                let code = if index < 8 { 30 + index } else { 82 + index };
+                write!(out, "\x1b[{code}m")
+            }
+            TerminalCaps::Ansi256 => write!(out, "\x1b[38;5;{}m", self.to_ansi256()),
+            TerminalCaps::TrueColor => {
+                let rgb = self.to_rgb();
+                write!(out, "\x1b[38;2;{};{};{}m", rgb.r, rgb.g, rgb.b)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Color {
+    /// Foreground SGR sequence for the nearest 256-color index
+    /// (`\x1b[38;5;Nm`).
+    #[must_use]
+    pub fn to_ansi256_fg(&self) -> String {
+        format!("\x1b[38;5;{}m", self.to_ansi256())
+    }
+
+    /// Background SGR sequence for the nearest 256-color index
+    /// (`\x1b[48;5;Nm`).
+    #[must_use]
+    pub fn to_ansi256_bg(&self) -> String {
+        format!("\x1b[48;5;{}m", self.to_ansi256())
+    }
+}
+
+/// Convert a slice of colors to foreground SGR sequences in one pass.
+///
+/// Each string is allocated once at its final size and colors are
+/// downgraded to what `caps` supports. For [`TerminalCaps::NoColor`] every
+/// entry is empty and nothing is allocated.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::color::{to_ansi_batch, TerminalCaps};
+/// use molten_brand::colors::molten;
+///
+/// let codes = to_ansi_batch(&[molten::PRIMARY], TerminalCaps::TrueColor);
+/// assert_eq!(codes, vec!["\x1b[38;2;249;115;22m"]);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn to_ansi_batch(colors: &[Color], caps: TerminalCaps) -> Vec<String> {
+    // Longest sequence per capability: `\x1b[97m`, `\x1b[38;5;255m`,
+    // `\x1b[38;2;255;255;255m`.
+    let capacity = match caps {
+        TerminalCaps::NoColor => 0,
+        TerminalCaps::Ansi16 => 5,
+        TerminalCaps::Ansi256 => 11,
+        TerminalCaps::TrueColor => 19,
+    };
+    colors
+        .iter()
+        .map(|color| {
+            let mut out = String::with_capacity(capacity);
+            let _ = color.write_ansi_fg(&mut out, caps);
+            out
+        })
+        .collect()
+}
+
+/// Parse a terminal's reply to the OSC 11 background color query.
+///
+/// Accepts `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` terminated by `BEL`, `ESC \` or
+/// the end of the string. Each component may have 1 - 4 hex digits and is
+/// scaled to 8 bits. Some terminals answer with `rgba:` and a fourth
+/// component; the alpha is ignored and the result is opaque.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::color::parse_osc11;
+/// use molten_brand::Color;
+///
+/// let reply = "\x1b]11;rgb:0f0f/0f0f/1a1a\x1b\\";
+/// assert_eq!(parse_osc11(reply), Some(Color::rgb(15, 15, 26)));
+/// assert_eq!(parse_osc11("garbage"), None);
+/// ```
+#[must_use]
+pub fn parse_osc11(reply: &str) -> Option<Color> {
+    let body = reply.strip_prefix("\x1b]11;")?;
+    let end = body.find(['\x07', '\x1b']).unwrap_or(body.len());
+    let body = &body[..end];
+    let (components, count) = match body.split_once(':')? {
+        ("rgb", rest) => (rest, 3),
+        ("rgba", rest) => (rest, 4),
+        _ => return None,
+    };
+    let mut channels = [0u8; 4];
+    let mut parts = components.split('/');
+    for channel in channels.iter_mut().take(count) {
+        *channel = scale_hex_component(parts.next()?)?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(Color::rgb(channels[0], channels[1], channels[2]))
+}
+
+/// Scale a 1 - 4 digit X11 hex color component to 8 bits, rounding.
+#[allow(clippy::cast_possible_truncation)]
+fn scale_hex_component(digits: &str) -> Option<u8> {
+    if digits.is_empty() || digits.len() > 4 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u32::from_str_radix(digits, 16).ok()?;
+    let max = (1u32 << (4 * digits.len())) - 1;
+    Some(((value * 255 + max / 2) / max) as u8)
+}
+
+/// Ask the terminal for its background color with the OSC 11 query.
+///
+/// Lets a terminal app pick light or dark tokens to match the user's actual
+/// terminal. The query is written to the controlling terminal (`/dev/tty`),
+/// which is switched to raw mode with `stty` for the duration and restored
+/// afterwards. Returns `None` if there is no terminal, `stty` fails, or no
+/// reply arrives within 100ms. Always `None` on non-Unix platforms.
+/// Requires the `terminal` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use molten_brand::color::query_terminal_background;
+///
+/// let light = query_terminal_background().is_some_and(|bg| bg.luminance() > 0.5);
+/// ```
+#[cfg(feature = "terminal")]
+#[must_use]
+pub fn query_terminal_background() -> Option<Color> {
+    #[cfg(unix)]
+    {
+        query_osc11_unix()
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+#[cfg(all(feature = "terminal", unix))]
+fn query_osc11_unix() -> Option<Color> {
+    use std::fs::{File, OpenOptions};
+    use std::io::{Read, Write};
+    use std::process::{Command, Stdio};
+
+    let tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let stty = |tty: &File, args: &[&str]| {
+        Command::new("stty")
+            .args(args)
+            .stdin(tty.try_clone().ok()?)
+            .stderr(Stdio::null())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+    };
+
+    let saved = String::from_utf8(stty(&tty, &["-g"])?.stdout).ok()?;
+    // Non-blocking reads that give up after 0.1s without input.
+    stty(&tty, &["raw", "-echo", "min", "0", "time", "1"])?;
+    let reply = (|| {
+        let mut tty = &tty;
+        tty.write_all(b"\x1b]11;?\x1b\\").ok()?;
+        tty.flush().ok()?;
+        let mut reply = Vec::new();
+        let mut buf = [0u8; 64];
+        while reply.len() < 256 && !reply.ends_with(b"\x07") && !reply.ends_with(b"\x1b\\") {
+            match tty.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => reply.extend_from_slice(&buf[..n]),
+            }
+        }
+        String::from_utf8(reply).ok()
+    })();
+    stty(&tty, &[saved.trim()]);
+    parse_osc11(&reply?)
+}
+
+impl From<Rgb> for Color {
+    fn from(rgb: Rgb) -> Self {
+        Self::Rgb(rgb)
+    }
+}
+
+impl From<Rgba> for Color {
+    fn from(rgba: Rgba) -> Self {
+        Self::Rgba(rgba)
+    }
+}
+
+/// Interpret an integer color literal.
+///
+/// Values below `0x1000000` are opaque `0xRRGGBB`; anything larger is
+/// `0xRRGGBBAA`. An RGBA value whose red channel is 0 falls in the RGB
+/// range, so use [`Color::from_rgba_u32`] when alpha is always present.
+///
+/// ```rust
+/// use molten_brand::Color;
+///
+/// assert_eq!(Color::from(0xF97316), Color::rgb(249, 115, 22));
+/// assert_eq!(Color::from(0x7C3AED66), Color::rgba(124, 58, 237, 102));
+/// ```
+impl From<u32> for Color {
+    fn from(value: u32) -> Self {
+        if value < 0x0100_0000 {
+            Self::from_rgb_u32(value)
+        } else {
+            Self::from_rgba_u32(value)
+        }
+    }
+}
+
+/// Pack a color as `0xRRGGBB` (the `Rgb` variant) or `0xRRGGBBAA` (`Rgba`).
+///
+/// Converting back with `Color::from` round-trips, except for `Rgba` colors
+/// with a red channel of 0; see [`Color::from_rgba_u32`].
+impl From<Color> for u32 {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::Rgb(rgb) => u32::from(rgb.r) << 16 | u32::from(rgb.g) << 8 | u32::from(rgb.b),
+            Color::Rgba(rgba) => {
+                u32::from(rgba.r) << 24
+                    | u32::from(rgba.g) << 16
+                    | u32::from(rgba.b) << 8
+                    | u32::from(rgba.a)
+            }
+        }
+    }
+}
+
+/// Scale each RGB channel by a factor, clamped to 0 - 255.
+///
+/// Alpha passes through unchanged, so `molten::PRIMARY * 0.8` is a darker
+/// orange with the same opacity.
+impl Mul<f32> for Color {
+    type Output = Self;
+
+    fn mul(self, factor: f32) -> Self {
+        let rgb = self.to_rgb();
+        let scale = |c: u8| to_byte(to_unit(c) * factor);
+        self.with_rgb(Rgb::new(scale(rgb.r), scale(rgb.g), scale(rgb.b)))
+    }
+}
+
+/// Add RGB channels, saturating at 255.
+///
+/// The result keeps the left operand's variant and alpha.
+impl Add for Color {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let (a, b) = (self.to_rgb(), other.to_rgb());
+        self.with_rgb(Rgb::new(
+            a.r.saturating_add(b.r),
+            a.g.saturating_add(b.g),
+            a.b.saturating_add(b.b),
+        ))
+    }
+}
+
+/// Subtract RGB channels, saturating at 0.
+///
+/// The result keeps the left operand's variant and alpha.
+impl Sub for Color {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let (a, b) = (self.to_rgb(), other.to_rgb());
+        self.with_rgb(Rgb::new(
+            a.r.saturating_sub(b.r),
+            a.g.saturating_sub(b.g),
+            a.b.saturating_sub(b.b),
+        ))
+    }
+}
+
+/// Serialize a [`Color`] as a compact `[r, g, b, a]` array.
+///
+/// Use with `#[serde(with = "molten_brand::color::serde_array")]`. The array
+/// is always RGBA, which suits numeric consumers and binary formats better
+/// than the tagged enum. On deserialization, an alpha of 255 yields
+/// [`Color::Rgb`].
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::Color;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Swatch {
+///     #[serde(with = "molten_brand::color::serde_array")]
+///     color: Color,
+/// }
+///
+/// let json = serde_json::to_string(&Swatch { color: Color::rgb(249, 115, 22) }).unwrap();
+/// assert_eq!(json, r#"{"color":[249,115,22,255]}"#);
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_array {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Color;
+
+    /// Serialize `color` as `[r, g, b, a]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error produced by the serializer.
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        let rgba = color.to_rgba();
+        [rgba.r, rgba.g, rgba.b, rgba.a].serialize(serializer)
+    }
+
+    /// Deserialize a color from `[r, g, b, a]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not an array of four bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let [r, g, b, a] = <[u8; 4]>::deserialize(deserializer)?;
+        Ok(if a == 255 {
+            Color::rgb(r, g, b)
+        } else {
+            Color::rgba(r, g, b, a)
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb_from_hex() {
+        let color = Rgb::from_hex("#F97316");
+        assert_eq!(color.r, 249);
+        assert_eq!(color.g, 115);
+        assert_eq!(color.b, 22);
+    }
+
+    #[test]
+    fn test_rgb_to_hex() {
+        let color = Rgb::new(249, 115, 22);
+        assert_eq!(color.hex(), "#F97316");
+    }
+
+    #[test]
+    fn test_rgba_alpha() {
+        let rgba = Rgba::from_rgb_alpha(Rgb::new(124, 58, 237), 0.4);
+        assert_eq!(rgba.a, 102); // 0.4 * 255 ≈ 102
+    }
+
+    #[test]
+    fn test_alpha_rounds_to_nearest() {
+        let purple = Rgb::new(124, 58, 237);
+        assert_eq!(Rgba::from_rgb_alpha(purple, 0.5).a, 128); // 127.5
+        assert_eq!(Rgba::from_rgb_alpha(purple, 0.3).a, 77); // 76.5
+        assert_eq!(Rgba::from_rgb_alpha(purple, 0.999).a, 255);
+        assert_eq!(Rgba::from_rgb_alpha(purple, -1.0).a, 0);
+
+        let lair = Color::rgb(124, 58, 237);
+        assert_eq!(lair.with_alpha(0.4), lair.at_opacity(40));
+        assert_eq!(lair.with_alpha(0.5), lair.at_opacity(50));
+        assert_eq!(lair.with_alpha(0.4), crate::products::lair::goblin::GLOW);
+    }
+
+    #[test]
+    fn test_blend_modes() {
+        let gray = Color::rgb(128, 128, 128);
+        assert_eq!(Color::WHITE.blend(&gray, BlendMode::Multiply), gray);
+        assert_eq!(Color::BLACK.blend(&gray, BlendMode::Screen), gray);
+        assert_eq!(gray.blend(&Color::WHITE, BlendMode::Normal), Color::WHITE);
+    }
+
+    #[test]
+    fn test_screen_stack() {
+        let half = Color::rgb(128, 128, 128);
+        let stacked = Color::screen_stack(&[half, half]);
+        assert!(stacked.luminance() > half.luminance());
+        assert_eq!(stacked, Color::rgb(192, 192, 192));
+        assert_eq!(
+            stacked,
+            Color::BLACK
+                .blend(&half, BlendMode::Screen)
+                .blend(&half, BlendMode::Screen)
+        );
+
+        let glow = crate::products::lair::goblin::GLOW;
+        let pulse = Color::screen_stack(&[glow, glow, glow]);
+        assert!(pulse.r() > Color::screen_stack(&[glow]).r());
+        assert_eq!(pulse.a(), 255);
+        assert_eq!(
+            Color::screen_stack(&[glow, half]),
+            Color::screen_stack(&[half, glow])
+        );
+        assert_eq!(Color::screen_stack(&[]), Color::BLACK);
+        assert_eq!(Color::screen_stack(&[Color::TRANSPARENT]), Color::BLACK);
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        let purple = Color::rgb(124, 58, 237);
+        let (h, s, l) = purple.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l), purple);
+        assert_eq!(
+            Color::rgb(255, 0, 0).rotate_hue(120.0),
+            Color::rgb(0, 255, 0)
+        );
+    }
+
+    #[test]
+    fn test_lab_distance() {
+        let (l, _, _) = Color::WHITE.to_lab();
+        assert!((l - 100.0).abs() < 0.01);
+        assert!(Color::BLACK.distance(&Color::BLACK) < f32::EPSILON);
+        assert!(Color::BLACK.distance(&Color::WHITE) > 99.0);
+    }
+
+    #[test]
+    fn test_display_from_str_round_trip() {
+        let tokens = [
+            Color::rgb(249, 115, 22),
+            Color::rgb(0, 0, 0),
+            Color::rgba(124, 58, 237, 77),
+            Color::rgba(255, 255, 255, 8),
+            Color::rgba(124, 58, 237, 102),
+            Color::TRANSPARENT,
+        ];
+        for color in tokens {
+            assert_eq!(color.to_string().parse::<Color>(), Ok(color));
+        }
+        for alpha in 0..=255 {
+            let color = Color::rgba(124, 58, 237, alpha);
+            assert_eq!(color.to_string().parse::<Color>(), Ok(color));
+        }
+        assert_eq!("#F97316".parse::<Color>(), Ok(Color::rgb(249, 115, 22)));
+        assert_eq!("#fff".parse::<Color>(), Ok(Color::WHITE));
+        assert!("rgb(1, 2)".parse::<Color>().is_err());
+        assert!("hsl(0, 0%, 0%)".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_mix() {
+        let gray = Color::BLACK.mix(&Color::WHITE, 0.5);
+        assert_eq!(gray, Color::rgb(128, 128, 128));
+        assert_eq!(Color::BLACK.mix(&Color::WHITE, 0.0), Color::BLACK);
+        assert_eq!(
+            Color::BLACK.mix(&Color::TRANSPARENT, 1.0),
+            Color::TRANSPARENT
+        );
+    }
+
+    #[test]
+    fn test_ramp_sample() {
+        const RAMP: ColorRamp = ColorRamp::new(&[Color::BLACK, Color::WHITE, Color::BLACK]);
+        assert_eq!(RAMP.sample(0.5), Color::WHITE);
+        assert_eq!(RAMP.sample(-1.0), Color::BLACK);
+        assert_eq!(
+            RAMP.steps(3),
+            vec![Color::BLACK, Color::WHITE, Color::BLACK]
+        );
+    }
+
+    #[test]
+    fn test_cell_style_ansi() {
+        let molten = Color::rgb(249, 115, 22);
+        let dark = Color::rgb(10, 10, 10);
+        let style = CellStyle::new(molten).with_bg(dark).bold();
+        assert_eq!(style.to_ansi(), "\x1b[1;38;2;249;115;22;48;2;10;10;10m");
+        assert_eq!(
+            style.paint("hi"),
+            "\x1b[1;38;2;249;115;22;48;2;10;10;10mhi\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_cell_style_composites_over_base() {
+        let style = CellStyle::new(Color::WHITE).with_bg(Color::rgba(255, 255, 255, 0));
+        assert_eq!(
+            style.to_ansi_over(Color::BLACK),
+            "\x1b[38;2;255;255;255;48;2;0;0;0m"
+        );
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 0.01);
+        assert!((Color::WHITE.contrast_ratio(&Color::WHITE) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_apca_polarity() {
+        let light = Color::rgb(250, 250, 250);
+        let dark = Color::rgb(24, 24, 27);
+        let light_on_dark = light.apca_contrast(&dark);
+        let dark_on_light = dark.apca_contrast(&light);
+        assert!(light_on_dark < 0.0);
+        assert!(dark_on_light > 0.0);
+        assert!((light_on_dark.abs() - dark_on_light).abs() > 1.0);
+        assert!((light.contrast_ratio(&dark) - dark.contrast_ratio(&light)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_channel_accessors() {
+        let rgb = Color::rgb(1, 2, 3);
+        assert_eq!((rgb.r(), rgb.g(), rgb.b(), rgb.a()), (1, 2, 3, 255));
+        let rgba = Color::rgba(4, 5, 6, 7);
+        assert_eq!((rgba.r(), rgba.g(), rgba.b(), rgba.a()), (4, 5, 6, 7));
+    }
+
+    #[test]
+    fn test_from_css_slash_alpha() {
+        let expected = Ok(Color::rgba(124, 58, 237, 77));
+        assert_eq!(Color::from_css("rgba(124 58 237 / 30%)"), expected);
+        assert_eq!(Color::from_css("rgb(124 58 237 / 30%)"), expected);
+        assert_eq!(Color::from_css("rgb(124, 58, 237 / 30%)"), expected);
+        assert_eq!(Color::from_css("rgba(124 58 237 / 0.3)"), expected);
+        assert_eq!(Color::from_css("rgba(124, 58, 237, 30%)"), expected);
+        assert_eq!(
+            Color::from_css("rgb(124 58 237)"),
+            Ok(Color::rgb(124, 58, 237))
+        );
+        assert_eq!(
+            Color::from_css("rgb(124 58 237 / 100%)"),
+            Ok(Color::rgba(124, 58, 237, 255))
+        );
+        assert!(Color::from_css("rgb(124 58 237 / 120%)").is_err());
+        assert!(Color::from_css("rgb(124 58 237 0.3)").is_err());
+    }
+
+    #[test]
+    fn test_lighten_darken() {
+        let gray = Color::rgb(128, 128, 128);
+        assert!(gray.lighten(0.2).luminance() > gray.luminance());
+        assert!(gray.darken(0.2).luminance() < gray.luminance());
+        assert_eq!(gray.lighten(1.0), Color::WHITE);
+        assert_eq!(
+            Color::rgba(128, 128, 128, 9).darken(1.0),
+            Color::rgba(0, 0, 0, 9)
+        );
+    }
+
+    #[test]
+    fn test_focus_ring() {
+        let purple = Color::rgb(124, 58, 237);
+        let ring = purple.focus_ring();
+        assert!(matches!(ring, Color::Rgba(_)));
+        assert_eq!(ring.a(), 128);
+        assert!(ring.to_hsl().2 > purple.to_hsl().2);
+    }
+
+    #[test]
+    fn test_ansi_indices() {
+        assert_eq!(Color::rgb(255, 0, 0).to_ansi256(), 196);
+        assert_eq!(Color::rgb(128, 128, 128).to_ansi256(), 244);
+        assert_eq!(Color::BLACK.to_ansi256(), 16);
+        assert_eq!(Color::rgb(250, 10, 10).to_ansi16(), 9);
+    }
+
+    #[test]
+    fn test_to_ansi_batch() {
+        let colors = [
+            Color::rgb(249, 115, 22),
+            Color::rgb(124, 58, 237),
+            Color::WHITE,
+        ];
+
+        let dumb = to_ansi_batch(&colors, TerminalCaps::NoColor);
+        assert_eq!(dumb.len(), colors.len());
+        assert!(dumb.iter().all(String::is_empty));
+
+        let basic = to_ansi_batch(&colors, TerminalCaps::Ansi16);
+        assert_eq!(basic[2], "\x1b[97m");
+
+        let extended = to_ansi_batch(&colors, TerminalCaps::Ansi256);
+        assert!(extended.iter().all(|code| code.starts_with("\x1b[38;5;")));
+
+        let full = to_ansi_batch(&colors, TerminalCaps::TrueColor);
+        assert_eq!(full[0], "\x1b[38;2;249;115;22m");
+        assert!(full.iter().all(|code| code.len() <= code.capacity()));
+    }
+
+    #[test]
+    fn test_perceptual_bucket() {
+        let molten = Color::rgb(249, 115, 22);
+        let almost = Color::rgb(248, 116, 23);
+        let purple = Color::rgb(124, 58, 237);
+        assert!(molten.same_bucket(&almost, 8));
+        assert!(!molten.same_bucket(&purple, 8));
+        assert_eq!(molten.perceptual_bucket(0), 0);
+        assert!(purple.perceptual_bucket(8) < 8 * 8 * 8);
+    }
+
+    #[test]
+    fn test_ease_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert!(ease(0.0, easing).abs() < 1e-6);
+            assert!((ease(1.0, easing) - 1.0).abs() < 1e-6);
+        }
+        assert!((ease(0.5, Easing::EaseInOut) - 0.5).abs() < 1e-6);
+        assert!(ease(0.25, Easing::EaseInOut) < 0.25);
+        assert!(ease(0.75, Easing::EaseOut) > 0.75);
+        assert!((ease(-1.0, Easing::EaseOut)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mix_eased() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
+        assert_eq!(
+            black.mix_eased(&white, 0.3, Easing::Linear),
+            black.mix(&white, 0.3)
+        );
+        assert!(black.mix_eased(&white, 0.3, Easing::EaseIn).r() < black.mix(&white, 0.3).r());
+        let ramp = ColorRamp::new(&[Color::BLACK, Color::WHITE]);
+        assert_eq!(ramp.sample_eased(0.5, Easing::EaseInOut), ramp.sample(0.5));
+    }
+
+    #[test]
+    fn test_hsl_setters() {
+        for color in [
+            Color::rgb(249, 115, 22),
+            Color::rgb(124, 58, 237),
+            Color::rgb(16, 185, 129),
+        ] {
+            assert_eq!(color.with_lightness(0.0), Color::BLACK);
+            assert_eq!(color.with_lightness(2.0), Color::WHITE);
+            let (_, s, _) = color.with_saturation(0.0).to_hsl();
+            assert!(s.abs() < 1e-6);
+        }
+
+        let orange = Color::rgb(249, 115, 22);
+        let (h, s, l) = orange.to_hsl();
+        let (h2, s2, l2) = orange.with_hue(200.0).to_hsl();
+        assert!((h2 - 200.0).abs() < 1.0);
+        assert!((s2 - s).abs() < 0.02 && (l2 - l).abs() < 0.01);
+        assert!((orange.with_hue(h).to_hsl().0 - h).abs() < 1.0);
+
+        let translucent = Color::rgba(249, 115, 22, 77);
+        assert_eq!(translucent.with_lightness(0.0), Color::rgba(0, 0, 0, 77));
+    }
+
+    #[test]
+    fn test_oklch_round_trip() {
+        for color in [
+            Color::rgb(249, 115, 22),
+            Color::rgb(124, 58, 237),
+            Color::rgb(10, 10, 10),
+            Color::WHITE,
+        ] {
+            let (l, c, h) = color.to_oklch();
+            assert_eq!(Color::from_oklch(l, c, h), color);
+        }
+        let (l, c, _) = Color::WHITE.to_oklch();
+        assert!((l - 1.0).abs() < 1e-3 && c < 1e-3);
+    }
+
+    #[test]
+    fn test_from_oklch_gamut_maps() {
+        let clipped = Color::from_oklch(0.7, 0.4, 40.0);
+        let (l, _, h) = clipped.to_oklch();
+        assert!((l - 0.7).abs() < 0.01);
+        assert!((h - 40.0).abs() < 2.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_array_round_trip() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Swatch {
+            #[serde(with = "serde_array")]
+            color: Color,
+        }
+
+        for color in [Color::rgb(249, 115, 22), Color::rgba(124, 58, 237, 77)] {
+            let json = serde_json::to_string(&Swatch { color }).unwrap();
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            let array = value["color"].as_array().unwrap();
+            assert_eq!(array.len(), 4);
+            assert_eq!(array[3], u64::from(color.a()));
+            assert_eq!(
+                serde_json::from_str::<Swatch>(&json).unwrap(),
+                Swatch { color }
+            );
+        }
+        assert!(serde_json::from_str::<Swatch>(r#"{"color":[1,2,3]}"#).is_err());
+    }
+
+    #[test]
+    fn test_mix_many() {
+        let midpoint = Color::mix_many(&[(Color::BLACK, 1.0), (Color::WHITE, 1.0)]);
+        assert_eq!(midpoint, Some(Color::rgb(188, 188, 188)));
+
+        let orange = Color::rgb(249, 115, 22);
+        assert_eq!(
+            Color::mix_many(&[(orange, 2.0), (orange, 5.0)]),
+            Some(orange)
+        );
+        assert_eq!(
+            Color::mix_many(&[(orange, 1.0), (Color::WHITE, -3.0)]),
+            Some(orange)
+        );
+        assert_eq!(
+            Color::mix_many(&[(Color::BLACK, 1.0), (Color::TRANSPARENT, 1.0)]),
+            Some(Color::rgba(0, 0, 0, 128))
+        );
+        assert_eq!(Color::mix_many(&[]), None);
+        assert_eq!(Color::mix_many(&[(orange, 0.0)]), None);
+    }
+
+    #[test]
+    fn test_at_opacity() {
+        let orange = Color::rgb(249, 115, 22);
+        assert_eq!(orange.at_opacity(20), Color::rgba(249, 115, 22, 51));
+        assert_eq!(orange.at_opacity(100).a(), 255);
+        assert_eq!(orange.at_opacity(0).a(), 0);
+        assert_eq!(orange.at_opacity(250).a(), 255);
+        assert_eq!(orange.at_opacity(50).at_opacity(30).a(), 77);
+    }
+
+    #[test]
+    fn test_parse_osc11() {
+        let cave = Some(Color::rgb(15, 15, 26));
+        assert_eq!(parse_osc11("\x1b]11;rgb:0f0f/0f0f/1a1a\x1b\\"), cave);
+        assert_eq!(parse_osc11("\x1b]11;rgb:0f0f/0f0f/1a1a\x07"), cave);
+        assert_eq!(parse_osc11("\x1b]11;rgb:0f/0f/1a"), cave);
+        assert_eq!(
+            parse_osc11("\x1b]11;rgb:f/8/0\x07"),
+            Some(Color::rgb(255, 136, 0))
+        );
+        assert_eq!(
+            parse_osc11("\x1b]11;rgba:ffff/ffff/ffff/0000\x07"),
+            Some(Color::WHITE)
+        );
+
+        assert_eq!(parse_osc11(""), None);
+        assert_eq!(parse_osc11("\x1b]10;rgb:0/0/0\x07"), None);
+        assert_eq!(parse_osc11("\x1b]11;rgb:0/0\x07"), None);
+        assert_eq!(parse_osc11("\x1b]11;rgb:0/0/0/0\x07"), None);
+        assert_eq!(parse_osc11("\x1b]11;rgb:00000/0/0\x07"), None);
+        assert_eq!(parse_osc11("\x1b]11;hsl:0/0/0\x07"), None);
+        assert_eq!(parse_osc11("\x1b]11;rgb:zz/0/0\x07"), None);
+        assert_eq!(parse_osc11("\x1b]11;rgb:+f/0/0\x07"), None);
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn test_u32_conversions() {
+        let orange = Color::rgb(249, 115, 22);
+        let glow = Color::rgba(124, 58, 237, 102);
+        assert_eq!(Color::from(0x00F9_7316), orange);
+        assert_eq!(Color::from(0x7C3A_ED66), glow);
+        assert_eq!(Color::from(0), Color::BLACK);
+        assert_eq!(u32::from(orange), 0x00F9_7316);
+        assert_eq!(u32::from(glow), 0x7C3A_ED66);
+        assert_eq!(orange.to_hex_literal(), "0xF97316");
+        assert_eq!(Color::BLACK.to_hex_literal(), "0x000000");
+        assert_eq!(glow.to_hex_literal(), "0x7C3AED66");
+
+        // Red 0 with alpha only survives the explicit RGBA path.
+        let teal = Color::rgba(0, 128, 128, 255);
+        assert_eq!(Color::from(u32::from(teal)), Color::rgb(128, 128, 255));
+        assert_eq!(Color::from_rgba_u32(u32::from(teal)), teal);
+        assert_eq!(Color::from_rgb_u32(0xFFF9_7316), orange);
+
+        let mut rng = XorShift(0x5EED);
+        for _ in 0..256 {
+            let color = Color::from(rng.next() as u32 | 0x0100_0000);
+            assert_eq!(Color::from(u32::from(color)), color);
+            let rgb = Color::from(rng.next() as u32 & 0x00FF_FFFF);
+            assert_eq!(Color::from(u32::from(rgb)), rgb);
+        }
+    }
+
+    #[test]
+    fn test_quantize() {
+        let levels: Vec<u8> = (0..16).map(|level| level * 17).collect();
+        for c in 0..=255u8 {
+            let q = Color::rgb(c, c, c).quantize(4).r();
+            let nearest = *levels.iter().min_by_key(|l| l.abs_diff(c)).unwrap();
+            assert_eq!(q, nearest, "channel {c}");
+        }
+
+        let glow = Color::rgba(124, 58, 237, 102);
+        assert_eq!(glow.quantize(8), glow);
+        assert_eq!(glow.quantize(12), glow);
+        assert_eq!(glow.quantize(0), Color::rgba(0, 0, 0, 102));
+        assert_eq!(Color::WHITE.quantize(1), Color::WHITE);
+        assert_eq!(Color::rgb(127, 128, 0).quantize(1), Color::rgb(0, 255, 0));
+        // RGB565 green has 6 bits; each channel is handled on its own.
+        assert_eq!(
+            Color::rgb(249, 115, 22).quantize(5),
+            Color::rgb(247, 115, 25)
+        );
+    }
+
+    #[test]
+    fn test_shadow() {
+        let orange = Color::rgb(249, 115, 22);
+        let alphas: Vec<u8> = (0..=6).map(|e| orange.shadow(e).a()).collect();
+        assert_eq!(alphas, [0, 26, 38, 51, 64, 77, 77]);
+        for elevation in 0..=5 {
+            let shadow = Color::WHITE.shadow(elevation);
+            assert!(shadow.r() <= 38 && shadow.g() <= 38 && shadow.b() <= 38);
+        }
+        assert_eq!(orange.shadow(2), orange.at_opacity(50).shadow(2));
+    }
+
+    #[test]
+    fn test_adjust_for_contrast() {
+        let dark = Color::rgb(15, 15, 26);
+        let light = Color::rgb(250, 250, 250);
+        let purple = Color::rgb(91, 33, 182);
+        assert_eq!(purple.adjust_for_contrast(&light, 4.5), purple);
+
+        let lifted = purple.adjust_for_contrast(&dark, 4.5);
+        assert!(lifted.contrast_ratio(&dark) >= 4.5);
+        assert!(lifted.contrast_ratio(&dark) < 4.8, "overshot: {lifted}");
+        assert!(lifted.to_hsl().2 > purple.to_hsl().2);
+
+        let lowered = Color::rgb(253, 186, 116).adjust_for_contrast(&light, 4.5);
+        assert!(lowered.contrast_ratio(&light) >= 4.5);
+
+        let glow = Color::rgba(124, 58, 237, 102).adjust_for_contrast(&dark, 7.0);
+        assert_eq!(glow.a(), 102);
+        // Unreachable targets settle on the extreme.
+        assert_eq!(purple.adjust_for_contrast(&dark, 30.0), Color::WHITE);
+    }
+
+    #[test]
+    fn test_border_on() {
+        let white = Color::rgb(250, 250, 250);
+        let black = Color::rgb(10, 10, 10);
+        let gray = Color::rgb(128, 128, 128);
+        for (fg, surface) in [
+            (white, black),
+            (black, white),
+            (black, black),
+            (white, white),
+            (gray, gray),
+        ] {
+            let border = fg.border_on(&surface);
+            assert!(
+                border.distance(&surface) >= 8.0,
+                "{fg} on {surface} gave {border}"
+            );
+            assert_eq!(border.a(), 255);
+            assert!(border.contrast_ratio(&surface) < 3.0);
+        }
+        assert_eq!(white.border_on(&black), black.mix(&white, 0.2));
+    }
+
+    #[test]
+    fn test_css_with_fallback() {
+        let css = Color::rgb(249, 115, 22).css_with_fallback();
+        let lines: Vec<&str> = css.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "#F97316");
+        assert!(lines[1].starts_with("oklch(70.") && lines[1].ends_with(')'));
+
+        let css = Color::rgba(124, 58, 237, 77).css_with_fallback();
+        assert!(css.starts_with("rgba(124, 58, 237, 0.30)\noklch("));
+        assert!(css.ends_with(" / 0.30)"));
+        assert!(Color::WHITE.css_with_fallback().contains('#'));
+    }
+
+    #[test]
+    fn test_from_label() {
+        assert_eq!(Color::from_label("goblin-1"), Color::from_label("goblin-1"));
+        assert_ne!(Color::from_label("goblin-1"), Color::from_label("goblin-2"));
+        assert_ne!(
+            Color::from_label("main"),
+            Color::from_label("feature/login")
+        );
+        // Pinned so a change to the hash or ranges is caught.
+        assert_eq!(
+            Color::from_label("lair"),
+            Color::from_hsl(189.0, 0.82, 0.63)
+        );
+
+        for label in ["", "a", "goblin-1", "feature/login", "🔥"] {
+            let (_, s, l) = Color::from_label(label).to_hsl();
+            assert!((0.6..=0.9).contains(&s), "{label}: saturation {s}");
+            assert!((0.48..=0.67).contains(&l), "{label}: lightness {l}");
+        }
+    }
+
+    #[test]
+    fn test_new_f32() {
+        assert_eq!(Rgb::new_f32(1.0, 0.0, 0.0), Rgb::new(255, 0, 0));
+        assert_eq!(Rgb::new_f32(2.0, -1.0, 0.5), Rgb::new(255, 0, 128));
+        assert_eq!(Rgba::new_f32(0.0, 0.0, 1.0, 0.3), Rgba::new(0, 0, 255, 77));
+
+        let rgb = Rgb::new(249, 115, 22);
+        let (r, g, b) = rgb.to_f32();
+        assert_eq!(Rgb::new_f32(r, g, b), rgb);
+        let (r, g, b) = Rgb::new_f32(0.3, 0.6, 0.9).to_f32();
+        for (x, y) in [(r, 0.3), (g, 0.6), (b, 0.9)] {
+            assert!((x - y).abs() <= 1.0 / 255.0);
+        }
+    }
+
+    #[test]
+    fn test_over() {
+        let red = Color::rgba(255, 0, 0, 128);
+        let blue = Color::rgba(0, 0, 255, 128);
+        let stacked = red.over(&blue);
+        assert!((stacked.to_rgba().alpha_f32() - 0.75).abs() < 0.01);
+        assert!(stacked.r() > stacked.b());
+
+        let base = Color::rgb(10, 10, 10);
+        assert_eq!(
+            Color::rgb(249, 115, 22).over(&base),
+            Color::rgb(249, 115, 22)
+        );
+        assert_eq!(Color::TRANSPARENT.over(&base), base);
+        assert_eq!(
+            Color::TRANSPARENT.over(&Color::TRANSPARENT),
+            Color::TRANSPARENT
+        );
+        assert_eq!(
+            Color::rgba(255, 255, 255, 128).over(&Color::BLACK),
+            Color::rgb(128, 128, 128)
+        );
+    }
+
+    #[test]
+    fn test_ansi256_sequences() {
+        let red = Color::rgb(255, 0, 0);
+        assert_eq!(red.to_ansi256(), 196);
+        assert_eq!(red.to_ansi256_fg(), "\x1b[38;5;196m");
+        assert_eq!(red.to_ansi256_bg(), "\x1b[48;5;196m");
+        assert_eq!(Color::BLACK.to_ansi256_bg(), "\x1b[48;5;16m");
+
+        let mut fg = String::new();
+        red.write_ansi_fg(&mut fg, TerminalCaps::Ansi256).unwrap();
+        assert_eq!(fg, red.to_ansi256_fg());
+    }
+
+    #[test]
+    fn test_color_operators() {
+        assert_eq!(Color::WHITE * 0.5, Color::rgb(128, 128, 128));
+        assert_eq!(Color::WHITE * -1.0, Color::BLACK);
+        assert_eq!(Color::rgb(200, 100, 50) * 2.0, Color::rgb(255, 200, 100));
+        assert_eq!(
+            Color::rgba(200, 100, 50, 77) * 0.5,
+            Color::rgba(100, 50, 25, 77)
+        );
+
+        let a = Color::rgb(200, 100, 10);
+        let b = Color::rgba(100, 100, 20, 10);
+        assert_eq!(a + b, Color::rgb(255, 200, 30));
+        assert_eq!(a - b, Color::rgb(100, 0, 0));
+        assert_eq!(b - a, Color::rgba(0, 0, 10, 10));
+    }
+
+    #[test]
+    fn test_luminance_approx() {
+        assert_eq!(Color::BLACK.luminance_approx(), 0);
+        assert_eq!(Color::WHITE.luminance_approx(), 255);
+        assert_eq!(Color::rgba(255, 255, 255, 0).luminance_approx(), 255);
+        assert!(
+            Color::rgb(0, 255, 0).luminance_approx() > Color::rgb(255, 0, 0).luminance_approx()
+        );
+    }
+
+    #[test]
+    fn test_warm_shift() {
+        let gray = Color::rgb(128, 128, 128);
+        let warm = gray.warm_shift(1000.0);
+        assert!(warm.r() > gray.r());
+        assert!(warm.b() < gray.b());
+
+        let cool = gray.warm_shift(-1000.0);
+        assert!(cool.r() < gray.r() && cool.b() > gray.b());
+        assert_eq!(gray.warm_shift(0.0), gray);
+        assert_eq!(Color::WHITE.warm_shift(5000.0).r(), 255);
+        assert_eq!(Color::rgba(128, 128, 128, 40).warm_shift(1000.0).a(), 40);
+    }
+
+    #[test]
+    fn test_ensure_variant() {
+        let translucent = Color::rgba(124, 58, 237, 77);
+        assert_eq!(
+            translucent.ensure_opaque(),
+            Color::Rgb(Rgb::new(124, 58, 237))
+        );
+        assert_eq!(
+            Color::rgb(1, 2, 3).ensure_alpha(),
+            Color::Rgba(Rgba::new(1, 2, 3, 255))
+        );
+        assert_eq!(translucent.ensure_alpha(), translucent);
+    }
+
+    #[test]
+    fn test_hex_case() {
+        let primary = Color::rgb(249, 115, 22);
+        assert_eq!(primary.hex_lower(), "#f97316");
+        assert_eq!(primary.hex_with_case(HexCase::Upper), primary.hex());
+        assert_eq!(primary.hex(), "#F97316");
+        assert_eq!(primary.with_alpha(0.5).hex_lower(), "#f97316");
+    }
+
+    #[test]
+    fn test_is_grayscale() {
+        assert!(crate::colors::neutral::SCALE_500.is_grayscale(10));
+        assert!(!crate::colors::neutral::SCALE_500.is_grayscale(0));
+        assert!(!crate::colors::molten::PRIMARY.is_grayscale(10));
+        assert!(Color::rgba(128, 128, 128, 0).is_grayscale(0));
+        assert!(Color::rgb(0, 255, 0).is_grayscale(255));
+    }
+
+    /// A small xorshift generator so the generative tests are reproducible
+    /// without extra dependencies.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n as u64) as usize
+        }
+    }
+
+    #[test]
+    fn test_try_from_hex_never_panics() {
+        const ALPHABET: &[char] = &[
+            '0', '7', '9', 'a', 'F', 'f', 'g', 'G', 'z', '#', ' ', '\t', '\n', '-', 'x', 'é', 'ß',
+            '🔥', '\u{0}', '\u{200b}',
+        ];
+        let mut rng = XorShift(0x5EED_CAFE_F00D_BEEF);
+        for _ in 0..20_000 {
+            let len = rng.below(12);
+            let input: String = (0..len)
+                .map(|_| ALPHABET[rng.below(ALPHABET.len())])
+                .collect();
+            if let Ok(color) = Color::try_from_hex(&input) {
+                let digits = input.strip_prefix('#').unwrap_or(&input);
+                assert!(digits.bytes().all(|b| b.is_ascii_hexdigit()), "{input:?}");
+                assert!(matches!(digits.len(), 3 | 4 | 6 | 8), "{input:?}");
+                let expected = if color.a() == 255 && digits.len() % 3 == 0 {
+                    color.hex()
+                } else {
+                    color.hex_rgba()
+                };
+                assert_eq!(Color::try_from_hex(&expected), Ok(color));
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn test_hex_round_trip_generated() {
+        let mut rng = XorShift(0x0123_4567_89AB_CDEF);
+        for _ in 0..10_000 {
+            let [r, g, b, a, ..] = rng.next().to_le_bytes();
+            let rgb = Color::rgb(r, g, b);
+            assert_eq!(Color::try_from_hex(&rgb.hex()), Ok(rgb));
+            assert_eq!(Color::try_from_hex(&rgb.hex_lower()), Ok(rgb));
+            let rgba = Color::rgba(r, g, b, a);
+            assert_eq!(Color::try_from_hex(&rgba.hex_rgba()), Ok(rgba));
+        }
+    }
+
+    #[test]
+    fn test_try_from_hex_edge_cases() {
+        for input in [
+            "",
+            "#",
+            "##FFFFFF",
+            "#FF#FFF",
+            " #FFFFFF",
+            "#FFFFFF ",
+            "FFFFF",
+            "#FFFFFFFFF",
+            "#ÉÉÉ",
+            "#🔥",
+            "+FFF",
+            "0xFFFFFF",
+        ] {
+            assert!(Color::try_from_hex(input).is_err(), "{input:?}");
+        }
+        assert_eq!(
+            Color::try_from_hex("#é"),
+            Err(ParseColorError::InvalidDigit)
+        );
+        assert_eq!(
+            Color::try_from_hex("#12345"),
+            Err(ParseColorError::InvalidLength(5))
+        );
+        assert_eq!(Rgb::from_hex("#fff"), Rgb::new(255, 255, 255));
+        assert_eq!(Color::rgb(1, 2, 3).hex_rgba(), "#010203FF");
+    }
+
+    #[test]
+    fn test_mix_srgb_vs_linear() {
+        let srgb = Color::BLACK.mix_srgb(&Color::WHITE, 0.5);
+        let linear = Color::BLACK.mix_linear(&Color::WHITE, 0.5);
+        assert_eq!(srgb, Color::rgb(128, 128, 128));
+        assert_eq!(linear, Color::rgb(188, 188, 188));
+        assert_eq!(srgb, Color::BLACK.mix(&Color::WHITE, 0.5));
+
+        let glow = Color::rgba(124, 58, 237, 0);
+        assert_eq!(glow.mix_linear(&Color::WHITE, 0.0), glow);
+        assert_eq!(glow.mix_linear(&Color::WHITE, 2.0), Color::WHITE);
+        assert_eq!(glow.mix_linear(&Color::WHITE, 0.5).a(), 128);
+    }
+
+    #[test]
+    fn test_mix_hsl_shortest_hue() {
+        let a = Color::from_hsl(350.0, 0.8, 0.5);
+        let b = Color::from_hsl(10.0, 0.8, 0.5);
+        let (h, s, _) = a.mix_hsl(&b, 0.5).to_hsl();
+        let drift = (h + 180.0).rem_euclid(360.0) - 180.0;
+        assert!(drift.abs() < 2.0, "hue {h}");
+        assert!((s - 0.8).abs() < 0.02);
+        let (h, _, _) = b.mix_hsl(&a, 0.5).to_hsl();
+        assert!(((h + 180.0).rem_euclid(360.0) - 180.0).abs() < 2.0);
+
+        // Gray endpoints borrow the other hue instead of pulling towards red.
+        let (h, _, _) = Color::rgb(128, 128, 128)
+            .mix_hsl(&Color::from_hsl(240.0, 1.0, 0.5), 0.5)
+            .to_hsl();
+        assert!((h - 240.0).abs() < 2.0);
+
+        let glow = Color::rgba(124, 58, 237, 0);
+        assert_eq!(glow.mix_hsl(&Color::WHITE, 0.5).a(), 128);
+        assert_eq!(a.mix_hsl(&b, 0.0), a);
+        assert_eq!(a.mix_hsl(&b, 1.0), b);
+    }
+
+    #[test]
+    fn test_recolor_to_hue() {
+        let molten = Color::rgb(249, 115, 22);
+        for hue in [0.0, 90.0, 145.0, 220.0, 300.0] {
+            let recolored = molten.recolor_to_hue(hue);
+            assert!((recolored.to_oklch().0 - molten.to_oklch().0).abs() < 0.01);
+            let drift = (recolored.to_oklch().2 - hue).rem_euclid(360.0);
+            assert!(drift.min(360.0 - drift) < 2.0);
+        }
+        let glass = Color::rgba(124, 58, 237, 77).recolor_to_hue(30.0);
+        assert_eq!(glass.a(), 77);
+    }
+
+    #[test]
+    fn test_color_delta() {
+        let molten = Color::rgb(249, 115, 22);
+        let delta = molten.delta(&Color::rgba(234, 88, 12, 128));
+        assert_eq!((delta.r, delta.g, delta.b, delta.a), (-15, -27, -10, -127));
+        assert!(delta.lightness < 0.0);
+        assert!((delta.distance - molten.distance(&Color::rgb(234, 88, 12))).abs() < 1e-4);
+
+        let same = molten.delta(&molten);
+        assert_eq!((same.r, same.g, same.b, same.a), (0, 0, 0, 0));
+        assert!(same.distance.abs() < 1e-4 && same.hue.abs() < 1e-4);
+
+        let wrap = Color::from_hsl(350.0, 1.0, 0.5).delta(&Color::from_hsl(10.0, 1.0, 0.5));
+        assert!((wrap.hue - 20.0).abs() < 1.0);
+        assert!(format!("{delta:?}").starts_with("ColorDelta { r: -15"));
+    }
+
+    #[test]
+    fn test_mix_pct() {
+        let gray = Color::WHITE.mix_pct(&Color::BLACK, 50);
+        assert_eq!(gray, Color::rgb(128, 128, 128));
+        assert_eq!(gray, Color::WHITE.mix(&Color::BLACK, 0.5));
+        assert_eq!(Color::WHITE.mix_pct(&Color::BLACK, 0), Color::WHITE);
+        assert_eq!(Color::WHITE.mix_pct(&Color::BLACK, 200), Color::BLACK);
+
+        let molten = Color::rgb(249, 115, 22);
+        let lair = Color::rgba(124, 58, 237, 0);
+        for percent in [10, 25, 33, 75, 90] {
+            let float = molten.mix(&lair, f32::from(percent) / 100.0);
+            let int = molten.mix_pct(&lair, percent);
+            assert!(float.r().abs_diff(int.r()) <= 1 && float.a().abs_diff(int.a()) <= 1);
+        }
     }
 }
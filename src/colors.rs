@@ -3,7 +3,13 @@
 //! This module contains the foundational color definitions shared across
 //! all Molten Labs products.
 
+use core::fmt;
+
 use crate::color::Color;
+#[cfg(feature = "std")]
+use crate::color::{ColorRamp, ParseColorError, Rgb, TerminalCaps, ANSI_RESET};
+use crate::products::{alloy, hearth, lair, Product};
+use crate::semantic;
 
 /// Forge brand colors - the parent company palette.
 ///
@@ -61,6 +67,36 @@ pub mod molten {
 
     /// Primary brand color (alias for `SCALE_500`).
     pub const PRIMARY: Color = SCALE_500;
+
+    /// The full scale as `(step, color)` pairs, lightest first.
+    pub const SCALE: [(u16, Color); 11] = [
+        (50, SCALE_50),
+        (100, SCALE_100),
+        (200, SCALE_200),
+        (300, SCALE_300),
+        (400, SCALE_400),
+        (500, SCALE_500),
+        (600, SCALE_600),
+        (700, SCALE_700),
+        (800, SCALE_800),
+        (900, SCALE_900),
+        (950, SCALE_950),
+    ];
+
+    /// The scale step (50 - 950) perceptually closest to `color`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::{colors::molten, Color};
+    ///
+    /// assert_eq!(molten::nearest_step(Color::rgb(236, 90, 14)), 600);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn nearest_step(color: Color) -> u16 {
+        super::nearest_step(&SCALE, color)
+    }
 }
 
 /// Neutral gray scale.
@@ -93,6 +129,186 @@ pub mod neutral {
     pub const SCALE_900: Color = Color::rgb(24, 24, 27); // #18181B
     /// Neutral 950 - near black.
     pub const SCALE_950: Color = Color::rgb(10, 10, 10); // #0A0A0A
+
+    /// The full scale as `(step, color)` pairs, lightest first.
+    pub const SCALE: [(u16, Color); 12] = [
+        (0, SCALE_0),
+        (50, SCALE_50),
+        (100, SCALE_100),
+        (200, SCALE_200),
+        (300, SCALE_300),
+        (400, SCALE_400),
+        (500, SCALE_500),
+        (600, SCALE_600),
+        (700, SCALE_700),
+        (800, SCALE_800),
+        (900, SCALE_900),
+        (950, SCALE_950),
+    ];
+
+    /// The scale step (0 - 950) perceptually closest to `color`.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn nearest_step(color: Color) -> u16 {
+        super::nearest_step(&SCALE, color)
+    }
+}
+
+/// Whether `scale` gets strictly darker from each step to the next.
+const fn is_monotonic(scale: &[(u16, Color)]) -> bool {
+    let mut i = 1;
+    while i < scale.len() {
+        if scale[i].1.luminance_approx() >= scale[i - 1].1.luminance_approx() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Scales are listed lightest first; putting a light color in a dark slot
+// should fail the build rather than ship.
+const _: () = assert!(
+    is_monotonic(&molten::SCALE),
+    "molten::SCALE is not monotonic"
+);
+const _: () = assert!(
+    is_monotonic(&neutral::SCALE),
+    "neutral::SCALE is not monotonic"
+);
+
+/// Find the step in `scale` with the smallest Lab distance to `color`.
+#[cfg(feature = "std")]
+fn nearest_step(scale: &[(u16, Color)], color: Color) -> u16 {
+    scale
+        .iter()
+        .min_by(|(_, a), (_, b)| a.distance(&color).total_cmp(&b.distance(&color)))
+        .map_or(500, |(step, _)| *step)
+}
+
+/// The steps of a generated [`Scale`], lightest first.
+#[cfg(feature = "std")]
+pub const SCALE_STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// A 50 - 950 color scale produced by [`ScaleBuilder`].
+///
+/// Index by step number: `scale[500]`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scale {
+    steps: [(u16, Color); 11],
+}
+
+#[cfg(feature = "std")]
+impl Scale {
+    /// The color at `step`, if it is one of [`SCALE_STEPS`].
+    #[must_use]
+    pub fn get(&self, step: u16) -> Option<Color> {
+        self.steps
+            .iter()
+            .find(|(s, _)| *s == step)
+            .map(|(_, color)| *color)
+    }
+
+    /// The full scale as `(step, color)` pairs, lightest first.
+    #[must_use]
+    pub const fn steps(&self) -> &[(u16, Color); 11] {
+        &self.steps
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::ops::Index<u16> for Scale {
+    type Output = Color;
+
+    /// # Panics
+    ///
+    /// Panics if `step` is not one of [`SCALE_STEPS`].
+    fn index(&self, step: u16) -> &Color {
+        match self.steps.iter().find(|(s, _)| *s == step) {
+            Some((_, color)) => color,
+            None => panic!("no scale step {step}"),
+        }
+    }
+}
+
+/// Builds a 50 - 950 [`Scale`] around a base color in OKLCH.
+///
+/// The base color becomes step 500. Lighter steps move its OKLCH lightness
+/// linearly towards `lightest` (reached at 50) and darker steps towards
+/// `darkest` (reached at 950). Chroma shrinks towards both ends by up to
+/// `chroma_falloff` of the base chroma, and hue is kept constant. The
+/// defaults (0.98, 0.27, 0.75) match the lightness range of the molten
+/// scale.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::ScaleBuilder;
+/// use molten_brand::products::lair;
+///
+/// let scale = ScaleBuilder::new(lair::PRIMARY).with_chroma_falloff(0.5).build();
+/// assert_eq!(scale[500], lair::PRIMARY);
+/// assert!(scale[50].luminance() > scale[950].luminance());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaleBuilder {
+    base: Color,
+    lightest: f32,
+    darkest: f32,
+    chroma_falloff: f32,
+}
+
+#[cfg(feature = "std")]
+impl ScaleBuilder {
+    /// Start a scale around `base` with the default endpoints and falloff.
+    #[must_use]
+    pub const fn new(base: Color) -> Self {
+        Self {
+            base,
+            lightest: 0.98,
+            darkest: 0.27,
+            chroma_falloff: 0.75,
+        }
+    }
+
+    /// Set the OKLCH lightness of step 50 and step 950 (each clamped to
+    /// 0.0 - 1.0).
+    #[must_use]
+    pub fn with_lightness(mut self, lightest: f32, darkest: f32) -> Self {
+        self.lightest = lightest.clamp(0.0, 1.0);
+        self.darkest = darkest.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set how much chroma is lost at the ends of the scale (0.0 keeps the
+    /// base chroma throughout, 1.0 fades to gray; clamped).
+    #[must_use]
+    pub fn with_chroma_falloff(mut self, falloff: f32) -> Self {
+        self.chroma_falloff = falloff.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Generate the scale.
+    #[must_use]
+    pub fn build(&self) -> Scale {
+        let (base_l, base_c, hue) = self.base.to_oklch();
+        let steps = SCALE_STEPS.map(|step| {
+            if step == 500 {
+                return (step, self.base.to_rgb().into());
+            }
+            let (t, target) = if step < 500 {
+                (f32::from(500 - step) / 450.0, self.lightest)
+            } else {
+                (f32::from(step - 500) / 450.0, self.darkest)
+            };
+            let lightness = base_l + (target - base_l) * t;
+            let chroma = base_c * (1.0 - self.chroma_falloff * t);
+            (step, Color::from_oklch(lightness, chroma, hue))
+        });
+        Scale { steps }
+    }
 }
 
 /// Surface colors for dark mode UI.
@@ -130,6 +346,83 @@ pub mod text {
 
     /// Brand-colored text.
     pub const BRAND: Color = Color::rgb(249, 115, 22); // #F97316
+
+    /// Text emphasis levels, from body copy down to disabled controls.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Emphasis {
+        /// Headings and body text.
+        #[default]
+        High,
+        /// Secondary text and captions.
+        Medium,
+        /// Hints and placeholders.
+        Low,
+        /// Disabled controls.
+        Disabled,
+    }
+
+    impl Emphasis {
+        /// All levels, from highest to lowest emphasis.
+        pub const ALL: [Self; 4] = [Self::High, Self::Medium, Self::Low, Self::Disabled];
+
+        /// Foreground opacity in percent, following the Material ladder.
+        #[must_use]
+        pub const fn opacity(self) -> u8 {
+            match self {
+                Self::High => 87,
+                Self::Medium => 60,
+                Self::Low => 45,
+                Self::Disabled => 38,
+            }
+        }
+    }
+
+    /// [`PRIMARY`] or [`INVERSE`], whichever contrasts more with `surface`.
+    ///
+    /// Ties go to [`PRIMARY`]. Alpha on the surface is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::colors::{molten, text};
+    /// use molten_brand::Color;
+    ///
+    /// assert_eq!(text::best_text_color(Color::BLACK), text::PRIMARY);
+    /// assert_eq!(text::best_text_color(molten::SCALE_200), text::INVERSE);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn best_text_color(surface: Color) -> Color {
+        if PRIMARY.contrast_ratio(&surface) >= INVERSE.contrast_ratio(&surface) {
+            PRIMARY
+        } else {
+            INVERSE
+        }
+    }
+
+    /// The text color for `level` on `surface`.
+    ///
+    /// Starts from [`best_text_color`] for the surface, applies the level's
+    /// [`Emphasis::opacity`] and composites the result over the surface. The
+    /// returned color is opaque, so it can be used in terminals and checked
+    /// with [`Color::contrast_ratio`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::colors::{surface, text::{self, Emphasis}};
+    ///
+    /// let body = text::emphasis(surface::BASE, Emphasis::High);
+    /// assert!(body.contrast_ratio(&surface::BASE) >= 4.5);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn emphasis(surface: Color, level: Emphasis) -> Color {
+        best_text_color(surface)
+            .at_opacity(level.opacity())
+            .over(&surface.ensure_opaque())
+    }
 }
 
 /// Glass/transparency effects.
@@ -149,6 +442,58 @@ pub mod glass {
     pub const BORDER_HOVER: Color = Color::rgba(249, 115, 22, 77); // ~30% opacity
 }
 
+/// Named brand gradients.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{gradients, molten};
+///
+/// assert_eq!(gradients::MOLTEN_FLAME.sample(0.5), molten::SCALE_600);
+/// ```
+#[cfg(feature = "std")]
+pub mod gradients {
+    use super::{lair, molten, neutral, semantic, ColorRamp};
+
+    /// Molten flame - molten 400 through 600 to 800.
+    ///
+    /// Used for marketing banners and hero backgrounds.
+    pub const MOLTEN_FLAME: ColorRamp =
+        ColorRamp::new(&[molten::SCALE_400, molten::SCALE_600, molten::SCALE_800]);
+
+    /// Goblin glow - Lair primary to accent.
+    ///
+    /// Used for Lair splash screens.
+    pub const GOBLIN_GLOW: ColorRamp = ColorRamp::new(&[lair::PRIMARY, lair::ACCENT]);
+
+    /// Error to success, through neutral 500.
+    ///
+    /// A diverging ramp for signed metrics such as latency deltas: sample
+    /// at 0.0 for the worst value, 0.5 for no change and 1.0 for the best.
+    pub const ERROR_SUCCESS: ColorRamp =
+        ColorRamp::new(&[semantic::ERROR, neutral::SCALE_500, semantic::SUCCESS]);
+}
+
+/// Build a diverging ramp from `low` through `mid` to `high`.
+///
+/// Unlike a sequential single-hue ramp, a diverging ramp has a meaningful
+/// center: sampling at 0.5 returns `mid` exactly.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{self, neutral};
+/// use molten_brand::products::{hearth, lair};
+///
+/// let ramp = colors::diverging_ramp(hearth::PRIMARY, neutral::SCALE_800, lair::PRIMARY);
+/// assert_eq!(ramp.sample(0.5), neutral::SCALE_800);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn diverging_ramp(low: Color, mid: Color, high: Color) -> ColorRamp {
+    ColorRamp::from_stops(vec![low, mid, high])
+}
+
 /// Helper to get a neutral color by scale (0-950).
 #[must_use]
 pub fn neutral_scale(scale: u16) -> Color {
@@ -185,3 +530,1817 @@ pub fn molten_scale(scale: u16) -> Color {
         _ => molten::SCALE_500, // Default to primary (includes 500)
     }
 }
+
+/// An error returned when parsing a GIMP palette fails.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GplError {
+    /// The input does not start with the `GIMP Palette` header.
+    MissingHeader,
+    /// A color row could not be parsed (1-based line number).
+    InvalidRow(usize),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for GplError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "missing \"GIMP Palette\" header"),
+            Self::InvalidRow(line) => write!(f, "invalid color row on line {line}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GplError {}
+
+/// Parse a GIMP palette (`.gpl`) into named colors.
+///
+/// The first non-blank line must be `GIMP Palette`. `Name:` and `Columns:`
+/// attributes, `#` comments and blank lines are skipped. Each remaining row
+/// is `r g b` followed by an optional name; unnamed rows are called
+/// `Untitled`, matching GIMP.
+///
+/// # Errors
+///
+/// Returns [`GplError::MissingHeader`] if the header is absent and
+/// [`GplError::InvalidRow`] for a row without three 0 - 255 channels.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{self, molten};
+///
+/// let gpl = "GIMP Palette\nName: Brand\n249 115 22\tMolten\n";
+/// let palette = colors::from_gpl(gpl).unwrap();
+/// assert_eq!(palette, vec![("Molten".to_string(), molten::PRIMARY)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn from_gpl(input: &str) -> Result<Vec<(String, Color)>, GplError> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    match lines.next() {
+        Some((_, "GIMP Palette")) => {}
+        _ => return Err(GplError::MissingHeader),
+    }
+
+    let mut colors = Vec::new();
+    for (number, line) in lines {
+        if line.starts_with('#') || line.starts_with("Name:") || line.starts_with("Columns:") {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let mut channel = || {
+            parts
+                .next()
+                .and_then(|p| p.parse::<u8>().ok())
+                .ok_or(GplError::InvalidRow(number))
+        };
+        let (r, g, b) = (channel()?, channel()?, channel()?);
+        let name = parts.collect::<Vec<_>>().join(" ");
+        let name = if name.is_empty() {
+            "Untitled".to_string()
+        } else {
+            name
+        };
+        colors.push((name, Color::rgb(r, g, b)));
+    }
+    Ok(colors)
+}
+
+/// Curated on-brand colors for categorical data, most distinct first.
+pub const CATEGORICAL: [Color; 8] = [
+    molten::PRIMARY,
+    lair::PRIMARY,
+    semantic::SUCCESS,
+    hearth::PRIMARY,
+    semantic::WARNING,
+    semantic::agent::COMPLETE,
+    semantic::ERROR,
+    lair::SECONDARY,
+];
+
+/// Minimum Lab distance between adjacent generated categorical colors.
+#[cfg(feature = "std")]
+const CATEGORICAL_MIN_DISTANCE: f32 = 20.0;
+
+/// The progress bar color at `pct` percent complete (0 - 100, clamped).
+///
+/// Walks the molten scale from 300 at 0% through 500 at 50% to 700 at
+/// 100%, mixing adjacent steps in sRGB. NaN counts as 0%. For status that
+/// should read as good or bad, use [`semantic::for_ratio`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{molten, progress_sequence};
+///
+/// assert_eq!(progress_sequence(0.0), molten::SCALE_300);
+/// assert_eq!(progress_sequence(50.0), molten::SCALE_500);
+/// assert_eq!(progress_sequence(100.0), molten::SCALE_700);
+/// ```
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub fn progress_sequence(pct: f32) -> Color {
+    const STOPS: [Color; 5] = [
+        molten::SCALE_300,
+        molten::SCALE_400,
+        molten::SCALE_500,
+        molten::SCALE_600,
+        molten::SCALE_700,
+    ];
+    let pct = if pct.is_nan() {
+        0.0
+    } else {
+        pct.clamp(0.0, 100.0)
+    };
+    let position = pct / 25.0;
+    let index = (position as usize).min(STOPS.len() - 2);
+    STOPS[index].mix(&STOPS[index + 1], position - index as f32)
+}
+
+/// Return `n` distinct, on-brand colors for charts and per-category data.
+///
+/// The first colors come from [`CATEGORICAL`]. Beyond that, colors are
+/// generated by rotating the molten primary's hue by the golden angle and
+/// cycling through three lightness levels, skipping any candidate that is
+/// within a Lab distance of 20 of its predecessor or duplicates an earlier
+/// color.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors;
+///
+/// let series = colors::categorical(12);
+/// assert_eq!(series.len(), 12);
+/// assert_eq!(series[0], colors::molten::PRIMARY);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn categorical(n: usize) -> Vec<Color> {
+    const GOLDEN_ANGLE: f32 = 137.508;
+    const LIGHTNESS: [f32; 3] = [0.55, 0.7, 0.4];
+
+    let mut out: Vec<Color> = CATEGORICAL.iter().copied().take(n).collect();
+    let (base_hue, saturation, _) = molten::PRIMARY.to_hsl();
+    let max_attempts = n.saturating_mul(64);
+    let mut step = 1_u16;
+    let mut attempts = 0;
+    while out.len() < n {
+        let hue = base_hue + f32::from(step) * GOLDEN_ANGLE;
+        let lightness = LIGHTNESS[usize::from(step) % LIGHTNESS.len()];
+        let candidate = Color::from_hsl(hue, saturation, lightness);
+        step = step.wrapping_add(1);
+        attempts += 1;
+
+        let distinct = out.last().map_or(true, |prev| {
+            prev.distance(&candidate) >= CATEGORICAL_MIN_DISTANCE
+        }) && !out.contains(&candidate);
+        if distinct || attempts > max_attempts {
+            out.push(candidate);
+        }
+    }
+    out
+}
+
+/// Return `n` distinct colors in the hue family of `product`.
+///
+/// The first color is the product primary. The rest keep its saturation
+/// and spread hues within 40 degrees either side of it, cycling through four
+/// lightness levels, with the same distinctness rule as [`categorical`].
+/// The output depends only on `product` and `n`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors;
+/// use molten_brand::products::{lair, Product};
+///
+/// let series = colors::categorical_for(Product::Lair, 6);
+/// assert_eq!(series.len(), 6);
+/// assert_eq!(series[0], lair::PRIMARY);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn categorical_for(product: Product, n: usize) -> Vec<Color> {
+    const HUE_SPREAD: f32 = 40.0;
+    const GOLDEN_RATIO: f32 = 0.618_034;
+    const LIGHTNESS: [f32; 4] = [0.6, 0.45, 0.75, 0.35];
+
+    let primary = product.primary();
+    let mut out: Vec<Color> = core::iter::once(primary).take(n).collect();
+    let (base_hue, saturation, _) = primary.to_hsl();
+    let max_attempts = n.saturating_mul(64);
+    let mut step = 1_u16;
+    let mut attempts = 0;
+    while out.len() < n {
+        let offset = (f32::from(step) * GOLDEN_RATIO).fract() * 2.0 - 1.0;
+        let hue = base_hue + offset * HUE_SPREAD;
+        let lightness = LIGHTNESS[usize::from(step) % LIGHTNESS.len()];
+        let candidate = Color::from_hsl(hue, saturation, lightness);
+        step = step.wrapping_add(1);
+        attempts += 1;
+
+        let distinct = out.last().map_or(true, |prev| {
+            prev.distance(&candidate) >= CATEGORICAL_MIN_DISTANCE
+        }) && !out.contains(&candidate);
+        if distinct || attempts > max_attempts {
+            out.push(candidate);
+        }
+    }
+    out
+}
+
+/// WCAG 2 contrast ratio of every foreground against every background.
+///
+/// Row `i`, column `j` holds `fgs[i].contrast_ratio(&bgs[j])`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{self, surface, text};
+///
+/// let matrix = colors::contrast_matrix(&[text::PRIMARY, text::MUTED], &[surface::BASE]);
+/// assert_eq!(matrix.len(), 2);
+/// assert!(matrix[0][0] > 4.5);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn contrast_matrix(fgs: &[Color], bgs: &[Color]) -> Vec<Vec<f32>> {
+    fgs.iter()
+        .map(|fg| bgs.iter().map(|bg| fg.contrast_ratio(bg)).collect())
+        .collect()
+}
+
+/// Render [`contrast_matrix`] as a Markdown table.
+///
+/// Rows are foregrounds and columns are backgrounds, both labelled by hex.
+/// Ratios below the WCAG AA body-text threshold (4.5) are marked with `✗`.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn contrast_table(fgs: &[Color], bgs: &[Color]) -> String {
+    use std::fmt::Write as _;
+
+    let mut out = String::from("| fg \\ bg |");
+    for bg in bgs {
+        let _ = write!(out, " {} |", bg.hex());
+    }
+    out.push_str("\n|---|");
+    out.push_str(&"---:|".repeat(bgs.len()));
+    out.push('\n');
+    for (fg, row) in fgs.iter().zip(contrast_matrix(fgs, bgs)) {
+        let _ = write!(out, "| {} |", fg.hex());
+        for ratio in row {
+            let mark = if ratio < 4.5 { " ✗" } else { "" };
+            let _ = write!(out, " {ratio:.2}{mark} |");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Parse a list of hex colors, collecting failures instead of stopping.
+///
+/// Each entry is parsed with [`Color::try_from_hex`]. Successes keep their
+/// input order; failures are returned with the index of the offending entry.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors;
+///
+/// let (colors, errors) = colors::parse_many(&["#F97316", "nope", "#7C3AED"]);
+/// assert_eq!(colors.len(), 2);
+/// assert_eq!(errors[0].0, 1);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn parse_many(hexes: &[&str]) -> (Vec<Color>, Vec<(usize, ParseColorError)>) {
+    let mut colors = Vec::new();
+    let mut errors = Vec::new();
+    for (index, hex) in hexes.iter().enumerate() {
+        match Color::try_from_hex(hex) {
+            Ok(color) => colors.push(color),
+            Err(err) => errors.push((index, err)),
+        }
+    }
+    (colors, errors)
+}
+
+/// Sort colors in place from darkest to lightest by OKLCH lightness.
+///
+/// OKLCH lightness tracks perceived brightness more closely than relative
+/// luminance, which makes it the better key for ordering legends. The sort
+/// is stable. Alpha is ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{self, molten};
+///
+/// let mut palette = [molten::SCALE_200, molten::SCALE_900, molten::SCALE_500];
+/// colors::sort_by_lightness(&mut palette);
+/// assert_eq!(palette, [molten::SCALE_900, molten::SCALE_500, molten::SCALE_200]);
+/// ```
+#[cfg(feature = "std")]
+pub fn sort_by_lightness(colors: &mut [Color]) {
+    colors.sort_by(|a, b| a.to_oklch().0.total_cmp(&b.to_oklch().0));
+}
+
+/// Sort colors in place from lightest to darkest by OKLCH lightness.
+///
+/// The reverse of [`sort_by_lightness`], matching the order of the brand
+/// scales.
+#[cfg(feature = "std")]
+pub fn sort_by_lightness_desc(colors: &mut [Color]) {
+    colors.sort_by(|a, b| b.to_oklch().0.total_cmp(&a.to_oklch().0));
+}
+
+/// Score how harmonious a set of colors is, from 0.0 (poor) to 1.0.
+///
+/// A heuristic for feedback on custom themes, combining two parts:
+///
+/// - **Hue evenness** `E`: sort the HSL hues of the chromatic colors
+///   (saturation above 0.1) around the wheel and take the `n` circular gaps
+///   `g`. With the ideal gap `i = 360 / n`,
+///   `E = 1 - Σ|g - i| / (2 · (360 - i))`, which is 1 for evenly spaced hues
+///   (complementary, triadic, ...) and 0 when they all coincide. Fewer than
+///   two chromatic colors give `E = 1`.
+/// - **Lightness spread** `L`: the range of HSL lightness over all colors,
+///   `L = min(range / 0.5, 1)`, rewarding sets with clear light and dark
+///   members.
+///
+/// The score is `0.7 · E + 0.3 · L`. Sets of fewer than two colors score
+/// 1.0.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{colors, Color};
+///
+/// let triad = [
+///     Color::from_hsl(0.0, 0.8, 0.3),
+///     Color::from_hsl(120.0, 0.8, 0.5),
+///     Color::from_hsl(240.0, 0.8, 0.7),
+/// ];
+/// assert!(colors::harmony_score(&triad) > 0.9);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn harmony_score(colors: &[Color]) -> f32 {
+    if colors.len() < 2 {
+        return 1.0;
+    }
+    let hsl: Vec<(f32, f32, f32)> = colors.iter().map(Color::to_hsl).collect();
+
+    let mut hues: Vec<f32> = hsl.iter().filter(|c| c.1 > 0.1).map(|c| c.0).collect();
+    let evenness = if hues.len() < 2 {
+        1.0
+    } else {
+        hues.sort_by(f32::total_cmp);
+        let ideal = 360.0 / hues.len() as f32;
+        let wrap = hues[0] + 360.0 - hues[hues.len() - 1];
+        let deviation: f32 = hues
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .chain([wrap])
+            .map(|gap| (gap - ideal).abs())
+            .sum();
+        (1.0 - deviation / (2.0 * (360.0 - ideal))).clamp(0.0, 1.0)
+    };
+
+    let (min, max) = hsl.iter().fold((1.0f32, 0.0f32), |(min, max), c| {
+        (min.min(c.2), max.max(c.2))
+    });
+    let spread = ((max - min) / 0.5).min(1.0);
+
+    0.7 * evenness + 0.3 * spread
+}
+
+/// Extract the `k` dominant colors from a set of pixels with k-means.
+///
+/// Takes raw pixels so it works with any decoder (for example, the
+/// `image` crate's `RgbImage::pixels`). Clustering runs in sRGB and is
+/// seeded deterministically: the first pixel, then repeatedly the pixel
+/// farthest from every centroid so far. The same input therefore always
+/// yields the same output.
+///
+/// Colors are returned most common first. Fewer than `k` colors are returned
+/// when the pixels contain fewer than `k` distinct values.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{colors, Rgb};
+///
+/// let pixels = [Rgb::new(249, 115, 22), Rgb::new(249, 115, 22), Rgb::new(10, 10, 10)];
+/// let dominant = colors::dominant_colors(&pixels, 2);
+/// assert_eq!(dominant[0], colors::molten::PRIMARY);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub fn dominant_colors(pixels: &[Rgb], k: usize) -> Vec<Color> {
+    const MAX_ITERATIONS: usize = 16;
+
+    let points: Vec<[f32; 3]> = pixels
+        .iter()
+        .map(|p| [f32::from(p.r), f32::from(p.g), f32::from(p.b)])
+        .collect();
+    let Some(&first) = points.first() else {
+        return Vec::new();
+    };
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let distance_sq = |a: &[f32; 3], b: &[f32; 3]| -> f32 {
+        a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+    };
+    let nearest = |centroids: &[[f32; 3]], point: &[f32; 3]| -> (usize, f32) {
+        centroids
+            .iter()
+            .map(|c| distance_sq(c, point))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((0, 0.0))
+    };
+
+    let mut centroids = vec![first];
+    while centroids.len() < k {
+        let farthest = points
+            .iter()
+            .map(|p| nearest(&centroids, p).1)
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        match farthest {
+            Some((index, d)) if d > 0.0 => centroids.push(points[index]),
+            _ => break,
+        }
+    }
+
+    let mut assignment = vec![0; points.len()];
+    let mut counts = vec![0_usize; centroids.len()];
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (slot, point) in assignment.iter_mut().zip(&points) {
+            let (index, _) = nearest(&centroids, point);
+            changed |= *slot != index;
+            *slot = index;
+        }
+
+        let mut sums = vec![[0.0_f32; 3]; centroids.len()];
+        counts.fill(0);
+        for (&index, point) in assignment.iter().zip(&points) {
+            for (sum, value) in sums[index].iter_mut().zip(point) {
+                *sum += value;
+            }
+            counts[index] += 1;
+        }
+        for ((centroid, sum), &count) in centroids.iter_mut().zip(&sums).zip(&counts) {
+            if count > 0 {
+                *centroid = sum.map(|s| s / count as f32);
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut clusters: Vec<(usize, [f32; 3])> = counts
+        .into_iter()
+        .zip(centroids)
+        .filter(|(count, _)| *count > 0)
+        .collect();
+    clusters.sort_by_key(|&(count, _)| std::cmp::Reverse(count));
+    clusters
+        .into_iter()
+        .map(|(_, [r, g, b])| {
+            let byte = |v: f32| v.round().clamp(0.0, 255.0) as u8;
+            Color::rgb(byte(r), byte(g), byte(b))
+        })
+        .collect()
+}
+
+/// Declare the token registry: the [`Token`] enum and the [`TOKENS`] table
+/// are generated from one list so they cannot drift apart. Each entry carries
+/// its stable id; a `const` assertion in the expansion rejects a duplicate
+/// id at compile time.
+macro_rules! token_registry {
+    ($($variant:ident($id:literal) => $name:literal = $color:expr,)*) => {
+        /// A named brand token.
+        ///
+        /// Each variant maps to one entry of [`TOKENS`]; [`Token::color`]
+        /// resolves with a single `match`, no string comparison.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use molten_brand::brand::Token;
+        ///
+        /// assert_eq!(Token::Molten500.name(), "molten.500");
+        /// assert_eq!(Token::from_name("molten.500"), Some(Token::Molten500));
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum Token {
+            $(
+                #[doc = concat!("`", $name, "`.")]
+                $variant,
+            )*
+        }
+
+        impl Token {
+            /// Every token, in registry order.
+            pub const ALL: &'static [Token] = &[$(Token::$variant,)*];
+
+            /// The dotted token name, e.g. `molten.500`.
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Token::$variant => $name,)*
+                }
+            }
+
+            /// The color this token resolves to.
+            #[must_use]
+            pub const fn color(self) -> Color {
+                match self {
+                    $(Token::$variant => $color,)*
+                }
+            }
+
+            /// The stable numeric id of this token, for compact binary
+            /// formats.
+            ///
+            /// Ids never change once assigned and are never reused; new tokens
+            /// take the next free id wherever they appear in the registry.
+            #[must_use]
+            pub const fn id(self) -> u16 {
+                match self {
+                    $(Token::$variant => $id,)*
+                }
+            }
+
+            /// Look up a token by its [`Token::id`].
+            #[must_use]
+            pub const fn from_id(id: u16) -> Option<Token> {
+                match id {
+                    $($id => Some(Token::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        // Reject a duplicate id at compile time.
+        const _: () = {
+            let ids: &[u16] = &[$($id,)*];
+            let mut i = 0;
+            while i < ids.len() {
+                let mut j = i + 1;
+                while j < ids.len() {
+                    assert!(ids[i] != ids[j], "duplicate token id");
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+
+        /// Every named brand token, keyed by its dotted path.
+        ///
+        /// Aliases such as `molten::PRIMARY` are omitted so each color appears once
+        /// under its canonical name.
+        pub const TOKENS: &[(&str, Color)] = &[$(($name, $color),)*];
+    };
+}
+
+token_registry! {
+    // Forge
+    ForgeBlack(0) => "forge.black" = forge::BLACK,
+    ForgeSteel(1) => "forge.steel" = forge::STEEL,
+    ForgeWhite(2) => "forge.white" = forge::WHITE,
+    ForgeMolten(3) => "forge.molten" = forge::MOLTEN,
+    ForgeEmber(4) => "forge.ember" = forge::EMBER,
+    ForgeIron(5) => "forge.iron" = forge::IRON,
+    // Molten scale
+    Molten50(6) => "molten.50" = molten::SCALE_50,
+    Molten100(7) => "molten.100" = molten::SCALE_100,
+    Molten200(8) => "molten.200" = molten::SCALE_200,
+    Molten300(9) => "molten.300" = molten::SCALE_300,
+    Molten400(10) => "molten.400" = molten::SCALE_400,
+    Molten500(11) => "molten.500" = molten::SCALE_500,
+    Molten600(12) => "molten.600" = molten::SCALE_600,
+    Molten700(13) => "molten.700" = molten::SCALE_700,
+    Molten800(14) => "molten.800" = molten::SCALE_800,
+    Molten900(15) => "molten.900" = molten::SCALE_900,
+    Molten950(16) => "molten.950" = molten::SCALE_950,
+    // Neutral scale
+    Neutral0(17) => "neutral.0" = neutral::SCALE_0,
+    Neutral50(18) => "neutral.50" = neutral::SCALE_50,
+    Neutral100(19) => "neutral.100" = neutral::SCALE_100,
+    Neutral200(20) => "neutral.200" = neutral::SCALE_200,
+    Neutral300(21) => "neutral.300" = neutral::SCALE_300,
+    Neutral400(22) => "neutral.400" = neutral::SCALE_400,
+    Neutral500(23) => "neutral.500" = neutral::SCALE_500,
+    Neutral600(24) => "neutral.600" = neutral::SCALE_600,
+    Neutral700(25) => "neutral.700" = neutral::SCALE_700,
+    Neutral800(26) => "neutral.800" = neutral::SCALE_800,
+    Neutral900(27) => "neutral.900" = neutral::SCALE_900,
+    Neutral950(28) => "neutral.950" = neutral::SCALE_950,
+    // Surfaces
+    SurfaceBase(29) => "surface.base" = surface::BASE,
+    SurfaceRaised(30) => "surface.raised" = surface::RAISED,
+    SurfaceOverlay(31) => "surface.overlay" = surface::OVERLAY,
+    SurfaceMuted(32) => "surface.muted" = surface::MUTED,
+    // Text
+    TextPrimary(33) => "text.primary" = text::PRIMARY,
+    TextSecondary(34) => "text.secondary" = text::SECONDARY,
+    TextMuted(35) => "text.muted" = text::MUTED,
+    TextInverse(36) => "text.inverse" = text::INVERSE,
+    TextBrand(37) => "text.brand" = text::BRAND,
+    // Glass
+    GlassBackground(38) => "glass.background" = glass::BACKGROUND,
+    GlassBackgroundHover(39) => "glass.background-hover" = glass::BACKGROUND_HOVER,
+    GlassBorder(40) => "glass.border" = glass::BORDER,
+    GlassBorderHover(41) => "glass.border-hover" = glass::BORDER_HOVER,
+    // Semantic
+    SemanticSuccess(42) => "semantic.success" = semantic::SUCCESS,
+    SemanticSuccessLight(43) => "semantic.success.light" = semantic::SUCCESS_LIGHT,
+    SemanticSuccessDark(44) => "semantic.success.dark" = semantic::SUCCESS_DARK,
+    SemanticWarning(45) => "semantic.warning" = semantic::WARNING,
+    SemanticWarningLight(46) => "semantic.warning.light" = semantic::WARNING_LIGHT,
+    SemanticWarningDark(47) => "semantic.warning.dark" = semantic::WARNING_DARK,
+    SemanticError(48) => "semantic.error" = semantic::ERROR,
+    SemanticErrorLight(49) => "semantic.error.light" = semantic::ERROR_LIGHT,
+    SemanticErrorDark(50) => "semantic.error.dark" = semantic::ERROR_DARK,
+    SemanticInfo(51) => "semantic.info" = semantic::INFO,
+    SemanticInfoLight(52) => "semantic.info.light" = semantic::INFO_LIGHT,
+    SemanticInfoDark(53) => "semantic.info.dark" = semantic::INFO_DARK,
+    // Agent status
+    AgentSpawning(54) => "agent.spawning" = semantic::agent::SPAWNING,
+    AgentRunning(55) => "agent.running" = semantic::agent::RUNNING,
+    AgentThinking(56) => "agent.thinking" = semantic::agent::THINKING,
+    AgentComplete(57) => "agent.complete" = semantic::agent::COMPLETE,
+    AgentFailed(58) => "agent.failed" = semantic::agent::FAILED,
+    AgentIdle(59) => "agent.idle" = semantic::agent::IDLE,
+    AgentPaused(60) => "agent.paused" = semantic::agent::PAUSED,
+    // Lair
+    LairPrimary(61) => "lair.primary" = lair::PRIMARY,
+    LairSecondary(62) => "lair.secondary" = lair::SECONDARY,
+    LairAccent(63) => "lair.accent" = lair::ACCENT,
+    LairTerminalBackground(64) => "lair.terminal.background" = lair::terminal::BACKGROUND,
+    LairTerminalForeground(65) => "lair.terminal.foreground" = lair::terminal::FOREGROUND,
+    LairTerminalCursor(66) => "lair.terminal.cursor" = lair::terminal::CURSOR,
+    LairTerminalSelection(67) => "lair.terminal.selection" = lair::terminal::SELECTION,
+    LairGoblinGlow(68) => "lair.goblin.glow" = lair::goblin::GLOW,
+    LairGoblinShadow(69) => "lair.goblin.shadow" = lair::goblin::SHADOW,
+    LairGoblinPulse(70) => "lair.goblin.pulse" = lair::goblin::PULSE,
+    LairSurfaceBase(71) => "lair.surface.base" = lair::surface::BASE,
+    LairSurfaceRaised(72) => "lair.surface.raised" = lair::surface::RAISED,
+    LairSurfaceTinted(73) => "lair.surface.tinted" = lair::surface::TINTED,
+    LairSurfaceBorder(74) => "lair.surface.border" = lair::surface::BORDER,
+    LairSurfaceBorderHover(75) => "lair.surface.border-hover" = lair::surface::BORDER_HOVER,
+    // Hearth
+    HearthPrimary(76) => "hearth.primary" = hearth::PRIMARY,
+    HearthSecondary(77) => "hearth.secondary" = hearth::SECONDARY,
+    HearthAccent(78) => "hearth.accent" = hearth::ACCENT,
+    HearthEditorialText(79) => "hearth.editorial.text" = hearth::editorial::TEXT,
+    HearthEditorialSecondary(80) => "hearth.editorial.secondary" = hearth::editorial::SECONDARY,
+    HearthEditorialTertiary(81) => "hearth.editorial.tertiary" = hearth::editorial::TERTIARY,
+    HearthEditorialBorder(82) => "hearth.editorial.border" = hearth::editorial::BORDER,
+    HearthContentBackground(83) => "hearth.content.background" = hearth::content::BACKGROUND,
+    HearthContentCard(84) => "hearth.content.card" = hearth::content::CARD,
+    HearthContentCardHover(85) => "hearth.content.card-hover" = hearth::content::CARD_HOVER,
+    HearthContentBorder(86) => "hearth.content.border" = hearth::content::BORDER,
+    // Alloy
+    AlloyPrimary(87) => "alloy.primary" = alloy::PRIMARY,
+    AlloySecondary(88) => "alloy.secondary" = alloy::SECONDARY,
+    AlloyAccent(89) => "alloy.accent" = alloy::ACCENT,
+    AlloySystemNeutral(90) => "alloy.system.neutral" = alloy::system::NEUTRAL,
+    AlloySystemSurface(91) => "alloy.system.surface" = alloy::system::SURFACE,
+    AlloyGlassBackground(92) => "alloy.glass.background" = alloy::glass::BACKGROUND,
+    AlloyGlassBackgroundHover(93) => "alloy.glass.background-hover" = alloy::glass::BACKGROUND_HOVER,
+    AlloyGlassBorder(94) => "alloy.glass.border" = alloy::glass::BORDER,
+    AlloyGlassBorderHover(95) => "alloy.glass.border-hover" = alloy::glass::BORDER_HOVER,
+}
+
+impl Token {
+    /// Look up a token by its dotted name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|token| token.name() == name)
+    }
+}
+
+/// Iterate over every named brand token.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{colors, Color};
+///
+/// let primary = colors::tokens().find(|(name, _)| *name == "molten.500");
+/// assert_eq!(primary.map(|(_, color)| color), Some(Color::rgb(249, 115, 22)));
+/// ```
+pub fn tokens() -> impl Iterator<Item = (&'static str, Color)> {
+    TOKENS.iter().copied()
+}
+
+/// An error returned by [`Palette::from_json`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteJsonError {
+    /// The input is not valid JSON (the parser's message).
+    Json(String),
+    /// The top-level value is not an object.
+    NotAnObject,
+    /// A value is not a valid hex color string.
+    InvalidColor {
+        /// The offending key.
+        key: String,
+        /// Why the value was rejected.
+        error: ParseColorError,
+    },
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for PaletteJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(message) => write!(f, "invalid JSON: {message}"),
+            Self::NotAnObject => write!(f, "expected a JSON object of name: hex pairs"),
+            Self::InvalidColor { key, error } => write!(f, "invalid color for \"{key}\": {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaletteJsonError {}
+
+/// Every token whose dotted name contains `substring`, ignoring ASCII case.
+///
+/// Results keep [`TOKENS`] order. An empty query matches every token.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors;
+///
+/// let glow = colors::search("GOBLIN.glow");
+/// assert_eq!(glow.len(), 1);
+/// assert_eq!(glow[0].0, "lair.goblin.glow");
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn search(substring: &str) -> Vec<(&'static str, Color)> {
+    let needle = substring.to_ascii_lowercase();
+    tokens()
+        .filter(|(name, _)| name.to_ascii_lowercase().contains(&needle))
+        .collect()
+}
+
+/// A named collection of colors with lookup and query helpers.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{molten, Palette};
+///
+/// let brand = Palette::brand();
+/// assert_eq!(brand.get("molten.500"), Some(molten::PRIMARY));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Palette {
+    entries: Vec<(String, Color)>,
+}
+
+#[cfg(feature = "std")]
+impl Palette {
+    /// Create a palette from named colors, keeping their order.
+    #[must_use]
+    pub fn new(entries: Vec<(String, Color)>) -> Self {
+        Self { entries }
+    }
+
+    /// Every brand token, in [`TOKENS`] order.
+    #[must_use]
+    pub fn brand() -> Self {
+        tokens()
+            .map(|(name, color)| (name.to_string(), color))
+            .collect()
+    }
+
+    /// Load a palette from a JSON object mapping names to hex colors.
+    ///
+    /// Values are parsed with [`Color::try_from_hex`]. Entries are ordered by
+    /// name, since JSON objects carry no order. Requires the `serde`
+    /// feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::colors::Palette;
+    /// use molten_brand::Color;
+    ///
+    /// let palette = Palette::from_json(r##"{"ink": "#111111", "paper": "#FAFAF5"}"##).unwrap();
+    /// assert_eq!(palette.nearest(&Color::BLACK).map(|(name, _)| name), Some("ink"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PaletteJsonError`] if the input is not a JSON object or a
+    /// value is not a valid hex color string; the error names the key.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, PaletteJsonError> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|err| PaletteJsonError::Json(err.to_string()))?;
+        let object = value.as_object().ok_or(PaletteJsonError::NotAnObject)?;
+        object
+            .iter()
+            .map(|(name, value)| {
+                let invalid = |error| PaletteJsonError::InvalidColor {
+                    key: name.clone(),
+                    error,
+                };
+                let hex = value
+                    .as_str()
+                    .ok_or_else(|| invalid(ParseColorError::UnknownFormat))?;
+                let color = Color::try_from_hex(hex).map_err(invalid)?;
+                Ok((name.clone(), color))
+            })
+            .collect()
+    }
+
+    /// Number of colors.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the palette has no colors.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over `(name, color)` pairs in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.entries
+            .iter()
+            .map(|(name, color)| (name.as_str(), *color))
+    }
+
+    /// The first color with the given name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, color)| color)
+    }
+
+    /// The entry perceptually closest to `color` (smallest Lab distance).
+    ///
+    /// Ties go to the earliest entry.
+    #[must_use]
+    pub fn nearest(&self, color: &Color) -> Option<(&str, Color)> {
+        self.iter()
+            .min_by(|(_, a), (_, b)| a.distance(color).total_cmp(&b.distance(color)))
+    }
+
+    /// The colors whose HSL hue lies in `min..=max` degrees.
+    ///
+    /// If `min` is greater than `max` the range wraps through 0, so
+    /// `filter_by_hue(330.0, 30.0)` selects reds. Achromatic colors (grays,
+    /// black and white) have no hue and are never included.
+    #[must_use]
+    pub fn filter_by_hue(&self, min: f32, max: f32) -> Self {
+        self.entries
+            .iter()
+            .filter(|(_, color)| {
+                let (hue, saturation, _) = color.to_hsl();
+                let in_range = if min <= max {
+                    (min..=max).contains(&hue)
+                } else {
+                    hue >= min || hue <= max
+                };
+                saturation > 0.0 && in_range
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// A copy sorted from darkest to lightest by relative luminance.
+    ///
+    /// The sort is stable, so equally bright colors keep their order.
+    #[must_use]
+    pub fn sorted_by_luminance(&self) -> Self {
+        let mut entries = self.entries.clone();
+        entries.sort_by(|(_, a), (_, b)| a.luminance().total_cmp(&b.luminance()));
+        Self { entries }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Vec<(String, Color)>> for Palette {
+    fn from(entries: Vec<(String, Color)>) -> Self {
+        Self::new(entries)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromIterator<(String, Color)> for Palette {
+    fn from_iter<I: IntoIterator<Item = (String, Color)>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+/// Whether colors are being resolved for a dark or a light UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThemeMode {
+    /// Dark UI; every token resolves to its defined value.
+    #[default]
+    Dark,
+    /// Light UI; surface, text and glass tokens use light variants and the
+    /// semantic base colors use their darker shades.
+    Light,
+}
+
+/// Light-mode replacements for the surface, text and glass tokens.
+///
+/// The palette is authored for dark UIs. Every surface, text and glass token
+/// (core and per-product) has an entry here. The semantic base colors fall
+/// back to their `_DARK` shades, which keep at least 3:1 against white; the
+/// bare bases do not (warning is about 2.2:1). Brand, scale, agent and the
+/// `.light`/`.dark` semantic shades are used unchanged.
+const LIGHT_MODE: &[(Token, Color)] = &[
+    (Token::SurfaceBase, Color::rgb(255, 255, 255)), // #FFFFFF
+    (Token::SurfaceRaised, Color::rgb(250, 250, 250)), // #FAFAFA
+    (Token::SurfaceOverlay, Color::rgb(244, 244, 245)), // #F4F4F5
+    (Token::SurfaceMuted, Color::rgb(228, 228, 231)), // #E4E4E7
+    (Token::TextPrimary, Color::rgb(10, 10, 10)),    // #0A0A0A
+    (Token::TextSecondary, Color::rgb(82, 82, 91)),  // #52525B
+    (Token::TextMuted, Color::rgb(113, 113, 122)),   // #71717A
+    (Token::TextInverse, Color::rgb(250, 250, 250)), // #FAFAFA
+    (Token::TextBrand, Color::rgb(194, 65, 12)),     // #C2410C
+    (Token::GlassBackground, Color::rgba(10, 10, 10, 8)), // ~3% opacity
+    (Token::GlassBackgroundHover, Color::rgba(234, 88, 12, 13)), // ~5% opacity
+    (Token::GlassBorder, Color::rgba(10, 10, 10, 15)), // ~6% opacity
+    (Token::GlassBorderHover, Color::rgba(234, 88, 12, 77)), // ~30% opacity
+    (Token::SemanticSuccess, semantic::SUCCESS_DARK),
+    (Token::SemanticWarning, semantic::WARNING_DARK),
+    (Token::SemanticError, semantic::ERROR_DARK),
+    (Token::SemanticInfo, semantic::INFO_DARK),
+    (Token::LairTerminalBackground, Color::rgb(250, 250, 252)), // #FAFAFC
+    (Token::LairTerminalForeground, Color::rgb(26, 26, 46)),    // #1A1A2E
+    (Token::LairTerminalCursor, Color::rgb(91, 33, 182)),       // #5B21B6
+    (Token::LairTerminalSelection, Color::rgba(124, 58, 237, 51)), // ~20% opacity
+    (Token::LairSurfaceBase, Color::rgb(250, 250, 252)),        // #FAFAFC
+    (Token::LairSurfaceRaised, Color::rgb(255, 255, 255)),      // #FFFFFF
+    (Token::LairSurfaceTinted, Color::rgb(237, 233, 254)),      // #EDE9FE
+    (Token::LairSurfaceBorder, Color::rgba(124, 58, 237, 51)),  // ~20% opacity
+    (Token::LairSurfaceBorderHover, Color::rgba(91, 33, 182, 102)), // ~40% opacity
+    (Token::HearthEditorialText, Color::rgb(23, 23, 23)),       // #171717
+    (Token::HearthEditorialSecondary, Color::rgb(82, 82, 82)),  // #525252
+    (Token::HearthEditorialTertiary, Color::rgb(115, 115, 115)), // #737373
+    (Token::HearthEditorialBorder, Color::rgb(229, 229, 229)),  // #E5E5E5
+    (Token::HearthContentBackground, Color::rgb(255, 255, 255)), // #FFFFFF
+    (Token::HearthContentCard, Color::rgb(250, 250, 250)),      // #FAFAFA
+    (Token::HearthContentCardHover, Color::rgb(245, 245, 245)), // #F5F5F5
+    (Token::HearthContentBorder, Color::rgb(229, 229, 229)),    // #E5E5E5
+    (Token::AlloySystemSurface, Color::rgb(250, 250, 250)),     // #FAFAFA
+    (Token::AlloyGlassBackground, Color::rgba(10, 10, 10, 8)),  // ~3% opacity
+    (
+        Token::AlloyGlassBackgroundHover,
+        Color::rgba(234, 88, 12, 13),
+    ), // ~5% opacity
+    (Token::AlloyGlassBorder, Color::rgba(10, 10, 10, 15)),     // ~6% opacity
+    (Token::AlloyGlassBorderHover, Color::rgba(234, 88, 12, 77)), // ~30% opacity
+];
+
+impl Token {
+    /// The color this token resolves to in the given mode.
+    #[must_use]
+    pub fn for_mode(self, mode: ThemeMode) -> Color {
+        match mode {
+            ThemeMode::Dark => self.color(),
+            ThemeMode::Light => LIGHT_MODE
+                .iter()
+                .find(|(token, _)| *token == self)
+                .map_or(self.color(), |(_, color)| *color),
+        }
+    }
+}
+
+/// Look up a token by dotted name and resolve it for a light or dark UI.
+///
+/// Returns `None` for an unknown name.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{self, ThemeMode};
+/// use molten_brand::Color;
+///
+/// assert_eq!(colors::for_mode("surface.base", ThemeMode::Light), Some(Color::WHITE));
+/// ```
+#[must_use]
+pub fn for_mode(token: &str, mode: ThemeMode) -> Option<Color> {
+    Token::from_name(token).map(|token| token.for_mode(mode))
+}
+
+/// Look up a token color by its dotted name.
+///
+/// Delegates to [`Token::from_name`]; prefer [`Token::color`] when the token
+/// is known at compile time. An optional `/NN` suffix applies
+/// [`Color::at_opacity`], so `"molten.500/20"` is molten 500 at 20% opacity.
+/// Returns `None` for an unknown name or an opacity outside 0 - 100.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{colors, products::lair};
+///
+/// assert_eq!(colors::by_name("lair.primary"), Some(lair::PRIMARY));
+/// assert_eq!(colors::by_name("lair.primary/40"), Some(lair::goblin::GLOW));
+/// assert_eq!(colors::by_name("lair.nope"), None);
+/// ```
+#[must_use]
+pub fn by_name(name: &str) -> Option<Color> {
+    let (name, opacity) = match name.split_once('/') {
+        Some((name, percent)) => (
+            name,
+            Some(percent.parse::<u8>().ok().filter(|p| *p <= 100)?),
+        ),
+        None => (name, None),
+    };
+    let color = Token::from_name(name)?.color();
+    Some(opacity.map_or(color, |percent| color.at_opacity(percent)))
+}
+
+/// Paint `text` in a named color for a terminal log line.
+///
+/// `name` is a [`semantic::SemanticKind`] name (`error`, `success`, ...) or
+/// anything [`by_name`] accepts. The text is wrapped in the foreground
+/// sequence for `caps` and [`ANSI_RESET`]; it is
+/// returned unchanged for [`TerminalCaps::NoColor`] or an unknown name.
+/// This is the formatting behind [`molten_println!`](crate::molten_println).
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::color::TerminalCaps;
+/// use molten_brand::colors;
+///
+/// let line = colors::paint_named("error", "build failed", TerminalCaps::TrueColor);
+/// assert_eq!(line, "\x1b[38;2;239;68;68mbuild failed\x1b[0m");
+/// assert_eq!(colors::paint_named("error", "build failed", TerminalCaps::NoColor), "build failed");
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn paint_named(name: &str, text: &str, caps: TerminalCaps) -> String {
+    let color = semantic::SemanticKind::from_name(name)
+        .map(semantic::SemanticKind::color)
+        .or_else(|| by_name(name));
+    let mut out = String::new();
+    match color {
+        Some(color) if caps != TerminalCaps::NoColor => {
+            let _ = color.write_ansi_fg(&mut out, caps);
+            out.push_str(text);
+            out.push_str(ANSI_RESET);
+        }
+        _ => out.push_str(text),
+    }
+    out
+}
+
+/// The opaque brand token perceptually closest to `color`.
+///
+/// Compares Lab distance against every token in [`Token::ALL`] except the
+/// translucent ones (glass, glow and similar), which would not stand in for
+/// a solid color. Many tokens share a color, so ties go to the canonical
+/// name: the `molten.*` and `neutral.*` scales first, then the product
+/// brand colors (`lair.primary` and so on), then `forge.*`, and only then
+/// the role aliases (`surface.*`, `semantic.*`, `agent.*` and the product
+/// sub-tokens). Alpha is ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{colors, Color};
+///
+/// assert_eq!(colors::nearest_token(Color::rgb(250, 118, 20)).name(), "molten.500");
+/// assert_eq!(colors::nearest_token(Color::rgb(120, 60, 230)).name(), "lair.primary");
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn nearest_token(color: Color) -> Token {
+    let color = color.ensure_opaque();
+    Token::ALL
+        .iter()
+        .copied()
+        .filter(|token| token.color().a() == 255)
+        .min_by(|a, b| {
+            a.color()
+                .distance(&color)
+                .total_cmp(&b.color().distance(&color))
+                .then(snap_rank(*a).cmp(&snap_rank(*b)))
+        })
+        .unwrap_or(Token::ForgeBlack)
+}
+
+/// Tie-break order for [`nearest_token`]; lower is more canonical.
+#[cfg(feature = "std")]
+fn snap_rank(token: Token) -> u8 {
+    let name = token.name();
+    let (group, rest) = name.split_once('.').unwrap_or((name, ""));
+    match group {
+        "molten" | "neutral" => 0,
+        "lair" | "hearth" | "alloy" if !rest.contains('.') => 1,
+        "forge" => 2,
+        _ => 3,
+    }
+}
+
+/// Snap each color to its nearest brand token, keeping input order.
+///
+/// Returns the token color and name for each input; see [`nearest_token`].
+/// Use it to normalize an imported design onto the brand palette.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{colors, products::lair, Color};
+///
+/// let snapped = colors::snap_to_brand(&[Color::rgb(120, 60, 230)]);
+/// assert_eq!(snapped, vec![(lair::PRIMARY, "lair.primary")]);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn snap_to_brand(colors: &[Color]) -> Vec<(Color, &'static str)> {
+    colors
+        .iter()
+        .map(|&color| {
+            let token = nearest_token(color);
+            (token.color(), token.name())
+        })
+        .collect()
+}
+
+/// The candidate perceptually farthest from `bg`.
+///
+/// Compares [`Color::distance`] (CIE Lab) against the background, ignoring
+/// alpha on both sides; ties go to the earlier candidate. Use it to pick a
+/// visible label color from a constrained brand set. With no candidates,
+/// falls back to [`text::best_text_color`].
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::colors::{self, molten};
+/// use molten_brand::products::lair;
+///
+/// let label = colors::most_distinct_from(lair::ACCENT, &[lair::PRIMARY, molten::PRIMARY]);
+/// assert_eq!(label, molten::PRIMARY);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn most_distinct_from(bg: Color, candidates: &[Color]) -> Color {
+    let bg = bg.ensure_opaque();
+    candidates
+        .iter()
+        .map(|&candidate| (candidate, candidate.ensure_opaque().distance(&bg)))
+        .reduce(|best, next| if next.1 > best.1 { next } else { best })
+        .map_or_else(|| text::best_text_color(bg), |(candidate, _)| candidate)
+}
+
+/// An error returned by [`resolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolveError {
+    /// The `/NN` opacity suffix is not an integer from 0 to 100.
+    InvalidOpacity,
+    /// The query is not a token name, product name, or CSS color.
+    Unknown,
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidOpacity => write!(f, "opacity must be an integer from 0 to 100"),
+            Self::Unknown => write!(f, "not a token name, product name, or CSS color"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResolveError {}
+
+/// Resolve a user-supplied color, e.g. the value of a `--color` flag.
+///
+/// Accepts, in order of precedence:
+///
+/// - token names (`"molten.500"`, see [`by_name`])
+/// - product names (`"lair"` resolves to the product's primary color)
+/// - CSS colors: hex, `rgb()` and `rgba()` (see [`Color::from_css`])
+///
+/// Token names, product names and hex colors take an optional `/NN`
+/// opacity suffix, so `"lair/40"` is the Lair primary at 40% opacity.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{colors::molten, products::lair, resolve};
+///
+/// assert_eq!(resolve("molten.500"), Ok(molten::PRIMARY));
+/// assert_eq!(resolve("lair/40"), Ok(lair::goblin::GLOW));
+/// assert_eq!(resolve("rgb(249, 115, 22)"), Ok(molten::PRIMARY));
+/// assert!(resolve("chartreuse-ish").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ResolveError::InvalidOpacity`] for a malformed opacity suffix
+/// and [`ResolveError::Unknown`] when nothing matches.
+pub fn resolve(query: &str) -> Result<Color, ResolveError> {
+    let query = query.trim();
+    let (base, opacity) = match query.rsplit_once('/') {
+        Some((base, percent)) if !query.ends_with(')') => {
+            let percent = percent
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|p| *p <= 100)
+                .ok_or(ResolveError::InvalidOpacity)?;
+            (base.trim(), Some(percent))
+        }
+        _ => (query, None),
+    };
+    let color = Token::from_name(base)
+        .map(Token::color)
+        .or_else(|| Product::from_name(base).map(Product::primary))
+        .or_else(|| Color::from_css(base).ok())
+        .ok_or(ResolveError::Unknown)?;
+    Ok(opacity.map_or(color, |percent| color.at_opacity(percent)))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_categorical_small() {
+        let colors = categorical(3);
+        assert_eq!(colors.len(), 3);
+        assert_ne!(colors[0], colors[1]);
+        assert_ne!(colors[1], colors[2]);
+        assert_ne!(colors[0], colors[2]);
+    }
+
+    #[test]
+    fn test_categorical_large() {
+        let colors = categorical(20);
+        assert_eq!(colors.len(), 20);
+        for (i, a) in colors.iter().enumerate() {
+            assert!(!colors[i + 1..].contains(a), "duplicate color {a}");
+        }
+        for pair in colors[CATEGORICAL.len()..].windows(2) {
+            assert!(pair[0].distance(&pair[1]) >= CATEGORICAL_MIN_DISTANCE);
+        }
+    }
+
+    #[test]
+    fn test_diverging_ramp_anchors() {
+        let ramp = diverging_ramp(hearth::PRIMARY, neutral::SCALE_100, molten::PRIMARY);
+        assert_eq!(ramp.sample(0.0), hearth::PRIMARY);
+        assert_eq!(ramp.sample(0.5), neutral::SCALE_100);
+        assert_eq!(ramp.sample(1.0), molten::PRIMARY);
+
+        assert_eq!(gradients::ERROR_SUCCESS.sample(0.0), semantic::ERROR);
+        assert_eq!(gradients::ERROR_SUCCESS.sample(0.5), neutral::SCALE_500);
+        assert_eq!(gradients::ERROR_SUCCESS.sample(1.0), semantic::SUCCESS);
+    }
+
+    #[test]
+    fn test_gradient_endpoints() {
+        assert_eq!(gradients::MOLTEN_FLAME.sample(0.0), molten::SCALE_400);
+        assert_eq!(gradients::MOLTEN_FLAME.sample(1.0), molten::SCALE_800);
+        assert_eq!(gradients::GOBLIN_GLOW.sample(0.0), lair::PRIMARY);
+        assert_eq!(gradients::GOBLIN_GLOW.sample(1.0), lair::ACCENT);
+    }
+
+    #[test]
+    fn test_from_gpl() {
+        let gpl = "GIMP Palette\n\
+                   Name: Molten\n\
+                   Columns: 2\n\
+                   # exported by a designer\n\
+                   \n\
+                   249 115  22\tMolten Primary\n\
+                   124  58 237\tGoblin Purple\n\
+                     0   0   0\n";
+        let palette = from_gpl(gpl).unwrap();
+        assert_eq!(
+            palette,
+            vec![
+                ("Molten Primary".to_string(), molten::PRIMARY),
+                ("Goblin Purple".to_string(), lair::PRIMARY),
+                ("Untitled".to_string(), Color::BLACK),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_gpl_errors() {
+        assert_eq!(
+            from_gpl("Not a palette\n1 2 3"),
+            Err(GplError::MissingHeader)
+        );
+        assert_eq!(
+            from_gpl("GIMP Palette\n1 2\n"),
+            Err(GplError::InvalidRow(2))
+        );
+        assert_eq!(
+            from_gpl("GIMP Palette\n1 2 300 X\n"),
+            Err(GplError::InvalidRow(2))
+        );
+    }
+
+    #[test]
+    fn test_nearest_step() {
+        assert_eq!(molten::nearest_step(molten::PRIMARY), 500);
+        assert_eq!(molten::nearest_step(Color::rgb(120, 44, 20)), 900);
+        assert_eq!(neutral::nearest_step(Color::rgb(115, 115, 118)), 500);
+        assert_eq!(neutral::nearest_step(Color::WHITE), 0);
+    }
+
+    #[test]
+    fn test_token_registry() {
+        assert_eq!(Token::ALL.len(), tokens().count());
+        for (token, (name, color)) in Token::ALL.iter().zip(tokens()) {
+            assert_eq!(token.name(), name);
+            assert_eq!(token.color(), color);
+            assert_eq!(by_name(name), Some(color));
+        }
+        assert_eq!(
+            Token::AlloyGlassBorderHover.color(),
+            alloy::glass::BORDER_HOVER
+        );
+        assert_eq!(by_name("molten.501"), None);
+    }
+
+    #[test]
+    fn test_token_ids() {
+        let mut ids: Vec<u16> = Token::ALL.iter().map(|token| token.id()).collect();
+        for &token in Token::ALL {
+            assert_eq!(Token::from_id(token.id()), Some(token));
+        }
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), Token::ALL.len());
+
+        // Ids are part of the wire format and must never change.
+        assert_eq!(Token::ForgeBlack.id(), 0);
+        assert_eq!(Token::Molten500.id(), 11);
+        assert_eq!(Token::AlloyGlassBorderHover.id(), 95);
+        assert_eq!(Token::from_id(96), None);
+        assert_eq!(Token::from_id(u16::MAX), None);
+    }
+
+    #[test]
+    fn test_contrast_matrix() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
+        let matrix = contrast_matrix(&[black, white, molten::PRIMARY], &[black, white]);
+        assert_eq!(matrix.len(), 3);
+        assert!(matrix.iter().all(|row| row.len() == 2));
+        assert!((matrix[0][0] - 1.0).abs() < 1e-3);
+        assert!((matrix[0][1] - 21.0).abs() < 1e-3);
+        assert!((matrix[1][0] - 21.0).abs() < 1e-3);
+        assert!((matrix[1][1] - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_contrast_table() {
+        let table = contrast_table(&[text::PRIMARY], &[surface::BASE, text::PRIMARY]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], "|---|---:|---:|");
+        assert!(lines[2].ends_with(" 1.00 ✗ |"));
+    }
+
+    #[test]
+    fn test_dominant_colors_two_color_image() {
+        let orange = Rgb::new(249, 115, 22);
+        let purple = Rgb::new(124, 58, 237);
+        let pixels: Vec<Rgb> = (0..100)
+            .map(|i| if i % 5 < 3 { orange } else { purple })
+            .collect();
+
+        assert_eq!(
+            dominant_colors(&pixels, 2),
+            vec![molten::PRIMARY, lair::PRIMARY]
+        );
+        assert_eq!(dominant_colors(&pixels, 5).len(), 2);
+        assert_eq!(dominant_colors(&pixels, 2), dominant_colors(&pixels, 2));
+        assert!(dominant_colors(&pixels, 0).is_empty());
+        assert!(dominant_colors(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_scale_builder_molten() {
+        let scale = ScaleBuilder::new(molten::PRIMARY).build();
+        assert!(scale[500].distance(&molten::PRIMARY) < 1.0);
+        for pair in scale.steps().windows(2) {
+            assert!(pair[0].1.luminance() > pair[1].1.luminance());
+        }
+        assert_eq!(scale.get(450), None);
+    }
+
+    #[test]
+    fn test_scale_builder_options() {
+        let gray = ScaleBuilder::new(lair::PRIMARY)
+            .with_chroma_falloff(1.0)
+            .with_lightness(1.0, 0.0)
+            .build();
+        assert_eq!(gray[50], Color::WHITE);
+        assert_eq!(gray[950], Color::BLACK);
+    }
+
+    #[test]
+    #[should_panic(expected = "no scale step 450")]
+    fn test_scale_index_panics() {
+        let _ = ScaleBuilder::new(molten::PRIMARY).build()[450];
+    }
+
+    #[test]
+    fn test_by_name_opacity_suffix() {
+        assert_eq!(
+            by_name("molten.500/20"),
+            Some(molten::SCALE_500.at_opacity(20))
+        );
+        assert_eq!(by_name("molten.500/20").map(|c| c.a()), Some(51));
+        assert_eq!(by_name("molten.500"), Some(molten::SCALE_500));
+        assert_eq!(by_name("molten.500").map(|c| c.a()), Some(255));
+        assert_eq!(by_name("molten.500/101"), None);
+        assert_eq!(by_name("molten.500/"), None);
+        assert_eq!(by_name("molten.501/20"), None);
+    }
+
+    #[test]
+    fn test_for_mode() {
+        let light = for_mode("surface.base", ThemeMode::Light).unwrap();
+        let dark = for_mode("surface.base", ThemeMode::Dark).unwrap();
+        assert!(light.luminance() > 0.95);
+        assert!(dark.luminance() < 0.01);
+        assert_eq!(dark, surface::BASE);
+        assert_eq!(
+            for_mode("molten.500", ThemeMode::Light),
+            Some(molten::SCALE_500)
+        );
+        assert_eq!(for_mode("surface.nope", ThemeMode::Light), None);
+    }
+
+    #[test]
+    fn test_light_mode_text_contrast() {
+        let background = Token::SurfaceBase.for_mode(ThemeMode::Light);
+        for token in [
+            Token::TextPrimary,
+            Token::TextSecondary,
+            Token::TextMuted,
+            Token::TextBrand,
+        ] {
+            assert!(token.for_mode(ThemeMode::Light).contrast_ratio(&background) >= 4.5);
+        }
+    }
+
+    #[test]
+    fn test_light_mode_covers_surfaces() {
+        for &token in Token::ALL {
+            let name = token.name();
+            let themed = [
+                "surface.",
+                "text.",
+                "glass.",
+                "terminal.",
+                "editorial.",
+                "content.",
+            ]
+            .iter()
+            .any(|part| name.starts_with(part) || name.contains(&format!(".{part}")))
+                || name == "alloy.system.surface";
+            if themed {
+                assert!(
+                    LIGHT_MODE.iter().any(|(light, _)| *light == token),
+                    "{name} has no light variant"
+                );
+            }
+        }
+        let white = Token::SurfaceBase.for_mode(ThemeMode::Light);
+        for token in [
+            Token::SemanticSuccess,
+            Token::SemanticWarning,
+            Token::SemanticError,
+            Token::SemanticInfo,
+        ] {
+            assert!(token.for_mode(ThemeMode::Light).contrast_ratio(&white) >= 3.0);
+        }
+    }
+
+    #[test]
+    fn test_palette_nearest() {
+        let brand = Palette::brand();
+        assert_eq!(brand.len(), TOKENS.len());
+        assert_eq!(
+            brand.nearest(&Color::rgb(250, 116, 23)),
+            Some(("forge.molten", molten::SCALE_500))
+        );
+        assert_eq!(
+            brand.nearest(&Color::rgb(120, 60, 230)).map(|(_, c)| c),
+            Some(lair::PRIMARY)
+        );
+        assert_eq!(Palette::default().nearest(&Color::BLACK), None);
+    }
+
+    #[test]
+    fn test_palette_sorted_by_luminance() {
+        let palette = Palette::new(vec![
+            ("white".to_string(), Color::WHITE),
+            ("orange".to_string(), molten::PRIMARY),
+            ("black".to_string(), Color::BLACK),
+        ]);
+        let sorted = palette.sorted_by_luminance();
+        let names: Vec<&str> = sorted.iter().map(|(n, _)| n).collect();
+        assert_eq!(names, ["black", "orange", "white"]);
+
+        let sorted = Palette::brand().sorted_by_luminance();
+        let lum: Vec<f32> = sorted.iter().map(|(_, c)| c.luminance()).collect();
+        assert!(lum.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_palette_filter_by_hue() {
+        let purples = Palette::brand().filter_by_hue(250.0, 290.0);
+        assert_eq!(purples.get("lair.primary"), Some(lair::PRIMARY));
+        assert_eq!(purples.get("molten.500"), None);
+        assert_eq!(purples.get("neutral.0"), None);
+
+        let reds = Palette::brand().filter_by_hue(350.0, 10.0);
+        assert_eq!(reds.get("semantic.error"), Some(semantic::ERROR));
+    }
+
+    #[test]
+    fn test_scales_monotonic() {
+        assert!(is_monotonic(&molten::SCALE));
+        assert!(is_monotonic(&neutral::SCALE));
+
+        let mut swapped = molten::SCALE;
+        swapped.swap(3, 4);
+        assert!(!is_monotonic(&swapped));
+        let mut swapped = neutral::SCALE;
+        swapped.swap(10, 11);
+        assert!(!is_monotonic(&swapped));
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let (colors, errors) = parse_many(&["#F97316", "#12345", "7C3AED", "#GG0000", "#fff"]);
+        assert_eq!(colors, vec![molten::PRIMARY, lair::PRIMARY, Color::WHITE]);
+        assert_eq!(
+            errors,
+            vec![
+                (1, ParseColorError::InvalidLength(5)),
+                (3, ParseColorError::InvalidDigit)
+            ]
+        );
+        assert_eq!(parse_many(&[]), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_sort_by_lightness() {
+        let scale: Vec<Color> = molten::SCALE.iter().map(|&(_, color)| color).collect();
+        let mut shuffled = scale.clone();
+        shuffled.swap(0, 7);
+        shuffled.swap(2, 9);
+        shuffled.swap(4, 10);
+        shuffled.reverse();
+
+        sort_by_lightness_desc(&mut shuffled);
+        assert_eq!(shuffled, scale);
+
+        sort_by_lightness(&mut shuffled);
+        assert_eq!(shuffled.first(), Some(&molten::SCALE_950));
+        assert_eq!(shuffled.last(), Some(&molten::SCALE_50));
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve("molten.500"), Ok(molten::PRIMARY));
+        assert_eq!(
+            resolve("molten.500/20"),
+            by_name("molten.500/20").ok_or(ResolveError::Unknown)
+        );
+        assert_eq!(resolve("Lair"), Ok(lair::PRIMARY));
+        assert_eq!(resolve("hearth"), Ok(hearth::PRIMARY));
+        assert_eq!(resolve("lair/40"), Ok(lair::goblin::GLOW));
+        assert_eq!(resolve("#7C3AED"), Ok(lair::PRIMARY));
+        assert_eq!(resolve(" 7c3aed "), Ok(lair::PRIMARY));
+        assert_eq!(resolve("#7C3AED/40"), Ok(lair::goblin::GLOW));
+        assert_eq!(resolve("rgba(124, 58, 237, 0.4)"), Ok(lair::goblin::GLOW));
+        assert_eq!(resolve("rgb(124 58 237 / 40%)"), Ok(lair::goblin::GLOW));
+
+        assert_eq!(resolve("molten.501"), Err(ResolveError::Unknown));
+        assert_eq!(resolve(""), Err(ResolveError::Unknown));
+        assert_eq!(resolve("lair/140"), Err(ResolveError::InvalidOpacity));
+        assert_eq!(resolve("lair/x"), Err(ResolveError::InvalidOpacity));
+    }
+
+    #[test]
+    fn test_text_emphasis() {
+        use text::Emphasis;
+
+        let ratios = Emphasis::ALL
+            .map(|level| text::emphasis(surface::BASE, level).contrast_ratio(&surface::BASE));
+        assert!(ratios[0] >= 4.5);
+        assert!(ratios.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(ratios[3] < ratios[0] / 2.0);
+
+        let on_light = text::emphasis(Color::WHITE, Emphasis::High);
+        assert!(on_light.contrast_ratio(&Color::WHITE) >= 4.5);
+        assert_eq!(on_light.a(), 255);
+    }
+
+    #[test]
+    fn test_most_distinct_from() {
+        let cave_purple = Color::rgb(46, 16, 101);
+        let candidates = [lair::ACCENT, lair::PRIMARY, molten::PRIMARY, text::PRIMARY];
+        let label = most_distinct_from(cave_purple, &candidates);
+        assert!(
+            label == molten::PRIMARY || label == text::PRIMARY,
+            "{label}"
+        );
+        assert_eq!(
+            most_distinct_from(cave_purple, &[lair::ACCENT, molten::SCALE_300]),
+            molten::SCALE_300
+        );
+
+        // Ties keep the first candidate; alpha is ignored.
+        let glow = lair::goblin::GLOW;
+        assert_eq!(
+            most_distinct_from(Color::BLACK, &[glow, glow.ensure_opaque()]),
+            glow
+        );
+        assert_eq!(most_distinct_from(Color::BLACK, &[]), text::PRIMARY);
+    }
+
+    #[test]
+    fn test_snap_to_brand() {
+        let inputs = [
+            Color::rgb(250, 118, 20),
+            Color::rgb(12, 12, 12),
+            Color::rgb(240, 70, 70),
+            Color::rgba(60, 130, 245, 128),
+            Color::rgb(112, 112, 120),
+            Color::rgb(120, 60, 230),
+        ];
+        let snapped = snap_to_brand(&inputs);
+        let names: Vec<&str> = snapped.iter().map(|(_, name)| *name).collect();
+        // Shared colors resolve to the canonical scale or product name.
+        assert_eq!(
+            names,
+            [
+                "molten.500",
+                "neutral.950",
+                "forge.ember",
+                "hearth.primary",
+                "neutral.500",
+                "lair.primary"
+            ]
+        );
+        assert!(snapped
+            .iter()
+            .all(|(color, name)| by_name(name) == Some(*color)));
+        assert!(snap_to_brand(&[]).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_palette_from_json() {
+        let palette = Palette::from_json(
+            r##"{"night": "#0B1020", "ember": "#E4572E", "sky": "#7FB7E6", "mint": "9FE2BF"}"##,
+        )
+        .unwrap();
+        assert_eq!(palette.len(), 4);
+        assert_eq!(palette.get("mint"), Some(Color::rgb(159, 226, 191)));
+        assert_eq!(
+            palette.nearest(&molten::PRIMARY).map(|(name, _)| name),
+            Some("ember")
+        );
+        assert_eq!(
+            palette.nearest(&Color::BLACK).map(|(name, _)| name),
+            Some("night")
+        );
+
+        assert_eq!(
+            Palette::from_json(r##"{"ok": "#fff", "bad": "#12345"}"##),
+            Err(PaletteJsonError::InvalidColor {
+                key: "bad".to_string(),
+                error: ParseColorError::InvalidLength(5)
+            })
+        );
+        assert!(matches!(
+            Palette::from_json(r#"{"n": 5}"#),
+            Err(PaletteJsonError::InvalidColor { key, .. }) if key == "n"
+        ));
+        assert_eq!(Palette::from_json("[]"), Err(PaletteJsonError::NotAnObject));
+        assert!(matches!(
+            Palette::from_json("{"),
+            Err(PaletteJsonError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_progress_sequence() {
+        assert!(progress_sequence(0.0).distance(&molten::SCALE_300) < 1.0);
+        assert!(progress_sequence(100.0).distance(&molten::SCALE_700) < 1.0);
+        assert_eq!(progress_sequence(-5.0), molten::SCALE_300);
+        assert_eq!(progress_sequence(250.0), molten::SCALE_700);
+        assert_eq!(progress_sequence(f32::NAN), molten::SCALE_300);
+        assert_eq!(progress_sequence(75.0), molten::SCALE_600);
+
+        let lum: Vec<f32> = (0_u8..=20)
+            .map(|i| progress_sequence(f32::from(i) * 5.0).luminance())
+            .collect();
+        assert!(lum.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn test_harmony_score() {
+        let triad = [
+            Color::from_hsl(0.0, 0.8, 0.3),
+            Color::from_hsl(120.0, 0.8, 0.5),
+            Color::from_hsl(240.0, 0.8, 0.7),
+        ];
+        let clump = [
+            Color::from_hsl(0.0, 0.8, 0.5),
+            Color::from_hsl(4.0, 0.8, 0.5),
+            Color::from_hsl(8.0, 0.8, 0.5),
+        ];
+        let (good, bad) = (harmony_score(&triad), harmony_score(&clump));
+        assert!(good > bad, "{good} <= {bad}");
+        assert!(good > 0.9 && bad < 0.1, "{good} {bad}");
+
+        assert!((harmony_score(&[]) - 1.0).abs() < f32::EPSILON);
+        assert!((harmony_score(&[molten::PRIMARY]) - 1.0).abs() < f32::EPSILON);
+        // Grays have no hue, so only the lightness spread counts against them.
+        let grays = [Color::rgb(100, 100, 100), Color::rgb(110, 110, 110)];
+        assert!((harmony_score(&grays) - 0.7).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_categorical_for_product() {
+        let lair = categorical_for(Product::Lair, 12);
+        assert_eq!(lair.len(), 12);
+        assert_eq!(lair[0], lair::PRIMARY);
+        let purple = lair
+            .iter()
+            .filter(|color| (220.0..=310.0).contains(&color.to_hsl().0))
+            .count();
+        assert!(purple >= 11, "only {purple} of 12 are purple");
+        for pair in lair.windows(2) {
+            assert!(pair[0].distance(&pair[1]) >= CATEGORICAL_MIN_DISTANCE);
+        }
+
+        assert_eq!(categorical_for(Product::Lair, 12), lair);
+        assert_eq!(categorical_for(Product::Hearth, 1), vec![hearth::PRIMARY]);
+        assert!(categorical_for(Product::Alloy, 0).is_empty());
+    }
+
+    #[test]
+    fn test_search() {
+        let lair = search("lair");
+        assert!(lair.len() > 1);
+        assert!(lair.iter().all(|(name, _)| name.starts_with("lair.")));
+        assert!(lair.contains(&("lair.primary", lair::PRIMARY)));
+        assert_eq!(search("LAIR"), lair);
+        assert!(search("zzz").is_empty());
+        assert_eq!(search("").len(), TOKENS.len());
+    }
+}
@@ -0,0 +1,1111 @@
+//! Export helpers.
+//!
+//! Render the brand tokens into formats consumed by other tools: CSS custom
+//! properties, LESS variables, Tailwind color maps, JSON, GIMP palettes,
+//! shareable coolors.co links, typography CSS classes, terminal cheatsheets,
+//! and terminal and editor themes (Terminal.app, Alacritty, Kitty, tmux,
+//! Zellij, Starship, oh-my-posh, delta).
+
+use std::fmt::Write;
+
+use crate::brand;
+use crate::color::{Color, TerminalCaps, ANSI_RESET};
+use crate::colors::neutral;
+use crate::colors::{self, forge, ThemeMode, Token};
+use crate::products::lair;
+use crate::semantic;
+use crate::spacing::{self, CssUnit};
+use crate::typography::presets;
+
+/// Format a color as a CSS value (hex for solid colors, `rgba()` otherwise).
+pub(crate) fn css_value(color: Color) -> String {
+    match color {
+        Color::Rgb(rgb) => rgb.hex(),
+        Color::Rgba(rgba) => rgba.css(),
+    }
+}
+
+/// Turn a dotted token name into a CSS identifier fragment.
+///
+/// ASCII letters are lowercased; anything other than letters, digits, `-`
+/// and `_` becomes `-`.
+fn ident(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' | '-' | '_' => c,
+            'A'..='Z' => c.to_ascii_lowercase(),
+            _ => '-',
+        })
+        .collect()
+}
+
+/// Join a prefix and a token name into a sanitized identifier.
+pub(crate) fn prefixed(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        ident(name)
+    } else {
+        format!("{}-{}", ident(prefix), ident(name))
+    }
+}
+
+/// Generate a `:root` block of CSS custom properties for every brand token.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::css_variables;
+///
+/// let css = css_variables("molten");
+/// assert!(css.contains("--molten-molten-500: #F97316;"));
+/// ```
+#[must_use]
+pub fn css_variables(prefix: &str) -> String {
+    let mut out = String::from(":root {\n");
+    for (name, color) in colors::tokens() {
+        let _ = writeln!(out, "  --{}: {};", prefixed(prefix, name), css_value(color));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Generate the [`css_variables`] block inside a CSS cascade layer.
+///
+/// The output is `@layer <layer> { :root { ... } }`, so the tokens take part
+/// in the page's layer order instead of competing on specificity. `layer`
+/// is written as given and may be a dotted nested name (`base.tokens`).
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::css_variables_layered;
+///
+/// let css = css_variables_layered("tokens", "mb");
+/// assert!(css.starts_with("@layer tokens {\n  :root {\n"));
+/// ```
+#[must_use]
+pub fn css_variables_layered(layer: &str, prefix: &str) -> String {
+    let mut out = format!("@layer {layer} {{\n");
+    for line in css_variables(prefix).lines() {
+        let _ = writeln!(out, "  {line}");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Generate CSS custom properties that follow the user's color scheme.
+///
+/// The `:root` block is the same as [`css_variables`] (the dark palette).
+/// It is followed by a `prefers-color-scheme: light` media query that
+/// overrides only the tokens with a light variant (see
+/// [`Token::for_mode`](crate::colors::Token::for_mode)).
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::css_with_schemes;
+///
+/// let css = css_with_schemes("mb");
+/// assert!(css.contains("@media (prefers-color-scheme: light) {"));
+/// ```
+#[must_use]
+pub fn css_with_schemes(prefix: &str) -> String {
+    let mut out = css_variables(prefix);
+    out.push_str("\n@media (prefers-color-scheme: light) {\n  :root {\n");
+    for &token in Token::ALL {
+        let light = token.for_mode(ThemeMode::Light);
+        if light != token.color() {
+            let _ = writeln!(
+                out,
+                "    --{}: {};",
+                prefixed(prefix, token.name()),
+                css_value(light)
+            );
+        }
+    }
+    out.push_str("  }\n}\n");
+    out
+}
+
+/// Build a CSS `color-mix()` expression blending two custom properties.
+///
+/// `base` and `with` are custom property names, with or without the leading
+/// `--`. `pct` is the share of `base` in the mix. Mixing happens in OKLCH so
+/// derived hover and active states stay perceptually even, and remain live
+/// when the root variables change.
+///
+/// Returns `None` if `pct` is greater than 100.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::css_mix;
+///
+/// assert_eq!(
+///     css_mix("molten-primary", "--molten-black", 80).as_deref(),
+///     Some("color-mix(in oklch, var(--molten-primary) 80%, var(--molten-black))")
+/// );
+/// assert_eq!(css_mix("molten-primary", "molten-black", 120), None);
+/// ```
+#[must_use]
+pub fn css_mix(base: &str, with: &str, pct: u8) -> Option<String> {
+    if pct > 100 {
+        return None;
+    }
+    let var = |name: &str| {
+        let name = name.trim_start_matches("--");
+        format!("var(--{name})")
+    };
+    Some(format!(
+        "color-mix(in oklch, {} {pct}%, {})",
+        var(base),
+        var(with)
+    ))
+}
+
+/// Generate typed `@property` registrations for the brand tokens.
+///
+/// Unlike the plain [`css_variables`] block, registered properties are
+/// type-checked by the browser and can be animated. Every color token gets
+/// `syntax: "<color>"` under the same name as in [`css_variables`]. The
+/// spacing scale (`space-0` ... `space-64`) and the semantic spacing aliases
+/// (`space-gap-md`, ...) get `syntax: "<length>"` in pixels. All properties
+/// inherit.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::css_typed_properties;
+///
+/// let css = css_typed_properties("mb");
+/// assert!(css.contains(
+///     "@property --mb-molten-500 {\n  syntax: \"<color>\";\n  inherits: true;\n  initial-value: #F97316;\n}\n"
+/// ));
+/// ```
+#[must_use]
+pub fn css_typed_properties(prefix: &str) -> String {
+    const SCALE_STEPS: [u16; 17] = [0, 1, 2, 3, 4, 5, 6, 8, 10, 12, 16, 20, 24, 32, 40, 48, 64];
+
+    let mut out = String::new();
+    let mut property = |name: &str, syntax: &str, initial: &str| {
+        let _ = writeln!(
+            out,
+            "@property --{} {{\n  syntax: \"{syntax}\";\n  inherits: true;\n  initial-value: {initial};\n}}",
+            prefixed(prefix, name)
+        );
+    };
+    for (name, color) in colors::tokens() {
+        property(name, "<color>", &css_value(color));
+    }
+    for step in SCALE_STEPS {
+        property(
+            &format!("space-{step}"),
+            "<length>",
+            &spacing::css(step, CssUnit::Px),
+        );
+    }
+    for (name, px) in spacing::semantic::ALL {
+        property(&format!("space-{name}"), "<length>", &format!("{px}px"));
+    }
+    out
+}
+
+/// Generate LESS variable declarations for every brand token.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::less_variables;
+///
+/// let less = less_variables("molten");
+/// assert!(less.contains("@molten-molten-500: #F97316;"));
+/// ```
+#[must_use]
+pub fn less_variables(prefix: &str) -> String {
+    let mut out = String::new();
+    for (name, color) in colors::tokens() {
+        let _ = writeln!(out, "@{}: {};", prefixed(prefix, name), css_value(color));
+    }
+    out
+}
+
+/// Generate a Tailwind `theme.colors` object, grouped by palette.
+///
+/// The output is a JavaScript object literal suitable for
+/// `theme: { extend: { colors: ... } }`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::tailwind_colors;
+///
+/// let colors = tailwind_colors();
+/// assert!(colors.contains("'500': '#F97316'"));
+/// ```
+#[must_use]
+pub fn tailwind_colors() -> String {
+    let mut out = String::from("{\n");
+    let mut group: Option<&str> = None;
+    for (name, color) in colors::tokens() {
+        let (head, rest) = name.split_once('.').unwrap_or((name, "DEFAULT"));
+        if group != Some(head) {
+            if group.is_some() {
+                out.push_str("  },\n");
+            }
+            let _ = writeln!(out, "  {head}: {{");
+            group = Some(head);
+        }
+        let _ = writeln!(out, "    '{}': '{}',", ident(rest), css_value(color));
+    }
+    if group.is_some() {
+        out.push_str("  },\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Version of the structured export formats.
+///
+/// Written as the `"$version"` field of [`to_json`]. Bump it whenever the
+/// shape of an export changes so downstream consumers can branch on it.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Serialize every brand token to a flat JSON object keyed by token name.
+///
+/// The object starts with a `"$version"` field holding [`FORMAT_VERSION`].
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::to_json;
+///
+/// let json = to_json();
+/// assert!(json.starts_with("{\n  \"$version\": 1,\n"));
+/// assert!(json.contains("\"molten.500\": \"#F97316\""));
+/// ```
+#[must_use]
+pub fn to_json() -> String {
+    let mut out = format!("{{\n  \"$version\": {FORMAT_VERSION}");
+    for (name, color) in colors::tokens() {
+        let _ = write!(out, ",\n  \"{name}\": \"{}\"", css_value(color));
+    }
+    out.push_str("\n}\n");
+    out
+}
+
+/// Number of swatch columns in exported GIMP palettes (one scale per row).
+const GPL_COLUMNS: usize = 11;
+
+/// Generate a GIMP palette (`.gpl`) containing every brand token.
+///
+/// The file loads in GIMP, Inkscape and Krita. Each row is labelled with
+/// its token name. GIMP palettes have no alpha channel, so translucent
+/// tokens are written as their opaque RGB.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::to_gpl;
+///
+/// let gpl = to_gpl("Molten Labs");
+/// assert!(gpl.starts_with("GIMP Palette\nName: Molten Labs\n"));
+/// assert!(gpl.contains("249 115  22\tmolten.500\n"));
+/// ```
+#[must_use]
+pub fn to_gpl(name: &str) -> String {
+    let mut out = format!("GIMP Palette\nName: {name}\nColumns: {GPL_COLUMNS}\n#\n");
+    for (token, color) in colors::tokens() {
+        let rgb = color.to_rgb();
+        let _ = writeln!(out, "{:3} {:3} {:3}\t{token}", rgb.r, rgb.g, rgb.b);
+    }
+    out
+}
+
+/// The curated core palette shared with designers.
+///
+/// The three product primaries (Molten Orange, Goblin Purple, Iron Blue),
+/// Ember for alerts, then Steel, Forge Black and near-white for neutrals.
+pub const CORE_PALETTE: [Color; 7] = [
+    forge::MOLTEN,
+    lair::PRIMARY,
+    forge::IRON,
+    forge::EMBER,
+    forge::STEEL,
+    forge::BLACK,
+    forge::WHITE,
+];
+
+/// The [`CORE_PALETTE`] as lowercase hex codes without the leading `#`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::hex_list;
+///
+/// assert_eq!(hex_list()[0], "f97316");
+/// ```
+#[must_use]
+pub fn hex_list() -> Vec<String> {
+    CORE_PALETTE
+        .iter()
+        .map(|color| {
+            let rgb = color.to_rgb();
+            format!("{:02x}{:02x}{:02x}", rgb.r, rgb.g, rgb.b)
+        })
+        .collect()
+}
+
+/// A shareable coolors.co link for the [`CORE_PALETTE`].
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::coolors_url;
+///
+/// assert!(coolors_url().starts_with("https://coolors.co/f97316-7c3aed-"));
+/// ```
+#[must_use]
+pub fn coolors_url() -> String {
+    format!("https://coolors.co/{}", hex_list().join("-"))
+}
+
+/// Generate a `.text-<preset>` CSS class for every typography preset.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::typography_css;
+///
+/// let css = typography_css();
+/// assert!(css.contains(".text-h1 {\n"));
+/// ```
+#[must_use]
+pub fn typography_css() -> String {
+    let mut out = String::new();
+    for (name, style) in presets::all() {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let _ = writeln!(out, ".text-{} {{", ident(name));
+        for declaration in style.to_css().lines() {
+            let _ = writeln!(out, "  {declaration}");
+        }
+        out.push_str("}\n");
+    }
+    out
+}
+
+/// The reference page for a token on the brand website.
+fn token_url(name: &str) -> String {
+    format!("{}/brand/tokens#{}", brand::WEBSITE, ident(name))
+}
+
+/// Render a terminal cheatsheet with a swatch, name and hex for every token.
+///
+/// Swatches are downgraded to what `caps` supports and omitted for
+/// [`TerminalCaps::NoColor`]. When `hyperlinks` is true, each token name is
+/// wrapped in an OSC 8 hyperlink to its reference page on the brand
+/// website; pass false for terminals that do not support OSC 8.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::color::TerminalCaps;
+/// use molten_brand::export::cheatsheet;
+///
+/// let plain = cheatsheet(TerminalCaps::NoColor, false);
+/// assert!(plain.lines().any(|line| line.starts_with("molten.500 ")));
+/// ```
+#[must_use]
+pub fn cheatsheet(caps: TerminalCaps, hyperlinks: bool) -> String {
+    let width = colors::tokens()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    let mut out = String::new();
+    for (name, color) in colors::tokens() {
+        if caps != TerminalCaps::NoColor {
+            let _ = color.write_ansi_fg(&mut out, caps);
+            let _ = write!(out, "██{ANSI_RESET} ");
+        }
+        if hyperlinks {
+            let _ = write!(out, "\x1b]8;;{}\x1b\\{name}\x1b]8;;\x1b\\", token_url(name));
+        } else {
+            out.push_str(name);
+        }
+        let pad = width - name.len();
+        let _ = writeln!(out, "{:pad$} {}", "", css_value(color));
+    }
+    out
+}
+
+/// The Goblin (Lair) terminal palette in ANSI order: black, red, green,
+/// yellow, blue, magenta, cyan, white, then the bright variants.
+///
+/// Normal colors come from the semantic and product tokens; bright colors
+/// are the same colors lightened by 0.1, except bright black (neutral 600)
+/// and bright white (the terminal foreground).
+fn terminal_ansi16() -> [Color; 16] {
+    let normal = [
+        lair::surface::RAISED,
+        semantic::ERROR,
+        semantic::SUCCESS,
+        semantic::WARNING,
+        semantic::INFO,
+        lair::PRIMARY,
+        semantic::agent::COMPLETE,
+        neutral::SCALE_400,
+    ];
+    let mut palette = [Color::BLACK; 16];
+    for (i, color) in normal.into_iter().enumerate() {
+        palette[i] = color;
+        palette[i + 8] = color.lighten(0.1);
+    }
+    palette[8] = neutral::SCALE_600;
+    palette[15] = lair::terminal::FOREGROUND;
+    palette
+}
+
+/// Encode bytes as standard, padded base64.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0_u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(char::from(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Archive a color the way Terminal.app stores it: a keyed-archiver
+/// `NSColor` plist (calibrated RGB, float components), base64-encoded.
+fn ns_color(color: Color) -> String {
+    let (r, g, b) = color.to_rgb().to_f32();
+    let archive = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+         <plist version=\"1.0\"><dict>\
+         <key>$archiver</key><string>NSKeyedArchiver</string>\
+         <key>$objects</key><array><string>$null</string><dict>\
+         <key>$class</key><dict><key>CF$UID</key><integer>2</integer></dict>\
+         <key>NSColorSpace</key><integer>1</integer>\
+         <key>NSRGB</key><data>{}</data></dict><dict>\
+         <key>$classes</key><array><string>NSColor</string><string>NSObject</string></array>\
+         <key>$classname</key><string>NSColor</string></dict></array>\
+         <key>$top</key><dict><key>root</key><dict><key>CF$UID</key><integer>1</integer></dict></dict>\
+         <key>$version</key><integer>100000</integer>\
+         </dict></plist>\n",
+        base64(format!("{r:.6} {g:.6} {b:.6}\0").as_bytes())
+    );
+    base64(archive.as_bytes())
+}
+
+/// Generate a macOS Terminal.app profile (`.terminal`) for the Goblin theme.
+///
+/// Open the file in Finder or import it from Terminal's settings. Colors are
+/// archived `NSColor` values, as Terminal.app expects; `name` becomes the
+/// profile name.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::macos_terminal;
+///
+/// let profile = macos_terminal("Goblin");
+/// assert!(profile.contains("<key>name</key>\n\t<string>Goblin</string>"));
+/// ```
+#[must_use]
+pub fn macos_terminal(name: &str) -> String {
+    const ANSI_KEYS: [&str; 8] = [
+        "Black", "Red", "Green", "Yellow", "Blue", "Magenta", "Cyan", "White",
+    ];
+
+    let mut colors: Vec<(String, Color)> = vec![
+        ("BackgroundColor".to_string(), lair::terminal::BACKGROUND),
+        ("TextColor".to_string(), lair::terminal::FOREGROUND),
+        ("TextBoldColor".to_string(), lair::terminal::FOREGROUND),
+        ("CursorColor".to_string(), lair::terminal::CURSOR),
+        ("SelectionColor".to_string(), lair::terminal::SELECTION),
+    ];
+    let palette = terminal_ansi16();
+    for (i, key) in ANSI_KEYS.iter().enumerate() {
+        colors.push((format!("ANSI{key}Color"), palette[i]));
+        colors.push((format!("ANSIBright{key}Color"), palette[i + 8]));
+    }
+
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+         <plist version=\"1.0\">\n<dict>\n",
+    );
+    for (key, color) in colors {
+        let _ = writeln!(
+            out,
+            "\t<key>{key}</key>\n\t<data>{}</data>",
+            ns_color(color)
+        );
+    }
+    let name = name.replace('&', "&amp;").replace('<', "&lt;");
+    let _ = writeln!(out, "\t<key>name</key>\n\t<string>{name}</string>");
+    out.push_str("\t<key>type</key>\n\t<string>Window Settings</string>\n");
+    out.push_str("\t<key>ProfileCurrentVersion</key>\n\t<real>2.07</real>\n");
+    out.push_str("</dict>\n</plist>\n");
+    out
+}
+
+/// ANSI color names in palette order, as used by Alacritty.
+const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// The terminal selection color, composited onto the background so it can
+/// be used by tools without alpha support.
+fn terminal_selection() -> Color {
+    lair::terminal::SELECTION.over(&lair::terminal::BACKGROUND)
+}
+
+/// Generate an Alacritty color theme (TOML) for the Goblin palette.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::alacritty_theme;
+///
+/// assert!(alacritty_theme().contains("background = \"#0F0F1A\""));
+/// ```
+#[must_use]
+pub fn alacritty_theme() -> String {
+    let palette = terminal_ansi16();
+    let mut out = format!(
+        "[colors.primary]\nbackground = \"{}\"\nforeground = \"{}\"\n\n\
+         [colors.cursor]\ntext = \"{}\"\ncursor = \"{}\"\n\n\
+         [colors.selection]\ntext = \"CellForeground\"\nbackground = \"{}\"\n",
+        lair::terminal::BACKGROUND.hex(),
+        lair::terminal::FOREGROUND.hex(),
+        lair::terminal::BACKGROUND.hex(),
+        lair::terminal::CURSOR.hex(),
+        terminal_selection().hex(),
+    );
+    for (section, colors) in [("normal", &palette[..8]), ("bright", &palette[8..])] {
+        let _ = write!(out, "\n[colors.{section}]\n");
+        for (name, color) in ANSI_NAMES.iter().zip(colors) {
+            let _ = writeln!(out, "{name} = \"{}\"", color.hex());
+        }
+    }
+    out
+}
+
+/// Generate a Kitty color theme (`.conf`) for the Goblin palette.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::kitty_theme;
+///
+/// assert!(kitty_theme().contains("cursor #7C3AED\n"));
+/// ```
+#[must_use]
+pub fn kitty_theme() -> String {
+    let mut out = format!(
+        "foreground {}\nbackground {}\ncursor {}\nselection_foreground {}\nselection_background {}\n",
+        lair::terminal::FOREGROUND.hex(),
+        lair::terminal::BACKGROUND.hex(),
+        lair::terminal::CURSOR.hex(),
+        lair::terminal::FOREGROUND.hex(),
+        terminal_selection().hex(),
+    );
+    for (i, color) in terminal_ansi16().iter().enumerate() {
+        let _ = writeln!(out, "color{i} {}", color.hex());
+    }
+    out
+}
+
+/// Generate tmux style options for the Goblin palette.
+///
+/// Source the file from `~/.tmux.conf`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::tmux_theme;
+///
+/// assert!(tmux_theme().contains("set -g pane-active-border-style \"fg=#7C3AED\""));
+/// ```
+#[must_use]
+pub fn tmux_theme() -> String {
+    let fg = lair::terminal::FOREGROUND.hex();
+    format!(
+        "set -g status-style \"bg={raised},fg={fg}\"\n\
+         set -g pane-border-style \"fg={tinted}\"\n\
+         set -g pane-active-border-style \"fg={primary}\"\n\
+         set -g message-style \"bg={primary},fg={fg}\"\n\
+         set -g mode-style \"bg={selection},fg={fg}\"\n",
+        raised = lair::surface::RAISED.hex(),
+        tinted = lair::surface::TINTED.hex(),
+        primary = lair::PRIMARY.hex(),
+        selection = terminal_selection().hex(),
+    )
+}
+
+/// Generate a Zellij theme block named `name`.
+///
+/// Zellij takes colors as space-separated RGB components. The eight normal
+/// ANSI colors match the other terminal themes, `fg` and `bg` are the Lair
+/// terminal colors, and `orange` is molten orange. Save the output under
+/// `~/.config/zellij/themes/` and select it with `theme "name"`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::zellij_theme;
+///
+/// let kdl = zellij_theme("molten");
+/// assert!(kdl.starts_with("themes {\n    molten {\n"));
+/// assert!(kdl.contains("        magenta 124 58 237\n"));
+/// ```
+#[must_use]
+pub fn zellij_theme(name: &str) -> String {
+    let ansi = terminal_ansi16();
+    let entries = [
+        ("fg", lair::terminal::FOREGROUND),
+        ("bg", lair::terminal::BACKGROUND),
+        ("black", ansi[0]),
+        ("red", ansi[1]),
+        ("green", ansi[2]),
+        ("yellow", ansi[3]),
+        ("blue", ansi[4]),
+        ("magenta", ansi[5]),
+        ("cyan", ansi[6]),
+        ("white", ansi[7]),
+        ("orange", colors::molten::PRIMARY),
+    ];
+    let mut out = format!("themes {{\n    {name} {{\n");
+    for (key, color) in entries {
+        let rgb = color.to_rgb();
+        let _ = writeln!(out, "        {key} {} {} {}", rgb.r, rgb.g, rgb.b);
+    }
+    out.push_str("    }\n}\n");
+    out
+}
+
+/// Generate a Starship prompt palette named `molten`.
+///
+/// Reference the colors from modules, e.g. `style = "fg:primary"`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::starship_palette;
+///
+/// assert!(starship_palette().starts_with("palette = \"molten\"\n"));
+/// ```
+#[must_use]
+pub fn starship_palette() -> String {
+    let entries = [
+        ("primary", lair::PRIMARY),
+        ("secondary", lair::SECONDARY),
+        ("accent", colors::molten::PRIMARY),
+        ("success", semantic::SUCCESS),
+        ("warning", semantic::WARNING),
+        ("error", semantic::ERROR),
+        ("info", semantic::INFO),
+        ("muted", colors::text::MUTED),
+    ];
+    let mut out = String::from("palette = \"molten\"\n\n[palettes.molten]\n");
+    for (name, color) in entries {
+        let _ = writeln!(out, "{name} = \"{}\"", color.hex());
+    }
+    out
+}
+
+/// Generate an oh-my-posh prompt theme.
+///
+/// Produces a single left-aligned powerline block with session, path, git
+/// and status segments, each with an explicit `foreground` and `background`
+/// hex color. `name` is used as the console title. Serialize the value with
+/// `serde_json::to_string_pretty` and save it as `name.omp.json`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::oh_my_posh;
+///
+/// let theme = oh_my_posh("molten");
+/// assert_eq!(theme["version"], 2);
+/// assert_eq!(theme["blocks"][0]["segments"][1]["background"], "#F97316");
+/// ```
+#[cfg(feature = "serde")]
+#[must_use]
+pub fn oh_my_posh(name: &str) -> serde_json::Value {
+    let segment = |kind: &str, fg: Color, bg: Color, template: &str| {
+        serde_json::json!({
+            "type": kind,
+            "style": "powerline",
+            "powerline_symbol": "\u{e0b0}",
+            "foreground": fg.hex(),
+            "background": bg.hex(),
+            "template": template,
+        })
+    };
+    let mut git = segment(
+        "git",
+        forge::BLACK,
+        semantic::SUCCESS,
+        " {{ .HEAD }}{{ if .Working.Changed }} \u{25cf}{{ end }} ",
+    );
+    git["background_templates"] = serde_json::json!([format!(
+        "{{{{ if or (.Working.Changed) (.Staging.Changed) }}}}{}{{{{ end }}}}",
+        semantic::WARNING.hex()
+    )]);
+    let mut status = segment("status", forge::WHITE, semantic::SUCCESS, " \u{2714} ");
+    status["background_templates"] = serde_json::json!([format!(
+        "{{{{ if gt .Code 0 }}}}{}{{{{ end }}}}",
+        semantic::ERROR.hex()
+    )]);
+    serde_json::json!({
+        "$schema": "https://raw.githubusercontent.com/JanDeDobbeleer/oh-my-posh/main/themes/schema.json",
+        "version": 2,
+        "console_title_template": format!("{name} \u{b7} {{{{ .Folder }}}}"),
+        "blocks": [{
+            "type": "prompt",
+            "alignment": "left",
+            "segments": [
+                segment("session", forge::WHITE, lair::PRIMARY, " {{ .UserName }} "),
+                segment("path", forge::BLACK, colors::molten::PRIMARY, " {{ .Path }} "),
+                git,
+                status,
+            ],
+        }],
+    })
+}
+
+/// Generate a git-delta feature named `molten` for diffs.
+///
+/// Include the file from `~/.gitconfig` and enable it with
+/// `features = molten` under `[delta]`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::delta_config;
+///
+/// assert!(delta_config().starts_with("[delta \"molten\"]\n"));
+/// ```
+#[must_use]
+pub fn delta_config() -> String {
+    let background = lair::terminal::BACKGROUND;
+    let tint = |color: Color, amount: f32| background.mix(&color, amount).hex();
+    format!(
+        "[delta \"molten\"]\n\
+         \tdark = true\n\
+         \tminus-style = syntax \"{}\"\n\
+         \tminus-emph-style = syntax \"{}\"\n\
+         \tplus-style = syntax \"{}\"\n\
+         \tplus-emph-style = syntax \"{}\"\n\
+         \tline-numbers-minus-style = \"{}\"\n\
+         \tline-numbers-plus-style = \"{}\"\n\
+         \tfile-style = \"{}\" bold\n",
+        tint(semantic::ERROR, 0.15),
+        tint(semantic::ERROR, 0.35),
+        tint(semantic::SUCCESS, 0.15),
+        tint(semantic::SUCCESS, 0.35),
+        semantic::ERROR.hex(),
+        semantic::SUCCESS.hex(),
+        lair::PRIMARY.hex(),
+    )
+}
+
+/// Every terminal and editor theme file as `(path, contents)` pairs.
+///
+/// Paths are relative to the XDG config directory (`~/.config`) and follow
+/// each tool's conventions: Alacritty, Kitty and Zellij themes live in
+/// `themes/`, and the tmux and delta files are meant to be sourced or
+/// included from the main config. Starship cannot include other files, so
+/// `starship/molten-palette.toml` is a fragment: merge its
+/// `palette = "molten"` line and `[palettes.molten]` table into
+/// `starship.toml` by hand. No entry overwrites a tool's main config.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::export::dotfiles_bundle;
+///
+/// for (path, contents) in dotfiles_bundle() {
+///     assert!(!path.is_empty() && !contents.is_empty());
+/// }
+/// ```
+#[must_use]
+pub fn dotfiles_bundle() -> Vec<(String, String)> {
+    vec![
+        (
+            "alacritty/themes/molten.toml".to_string(),
+            alacritty_theme(),
+        ),
+        ("kitty/themes/molten.conf".to_string(), kitty_theme()),
+        ("tmux/molten.conf".to_string(), tmux_theme()),
+        (
+            "zellij/themes/molten.kdl".to_string(),
+            zellij_theme("molten"),
+        ),
+        (
+            "starship/molten-palette.toml".to_string(),
+            starship_palette(),
+        ),
+        ("delta/molten.gitconfig".to_string(), delta_config()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_css_variables() {
+        let css = css_variables("mb");
+        assert!(css.starts_with(":root {\n"));
+        assert!(css.contains("--mb-lair-primary: #7C3AED;"));
+        assert!(css.contains("--mb-glass-border: rgba(255, 255, 255, 0.06);"));
+    }
+
+    #[test]
+    fn test_css_typed_properties() {
+        let css = css_typed_properties("molten");
+        assert!(css.starts_with("@property --molten-forge-black {\n"));
+        assert!(css.contains(concat!(
+            "@property --molten-lair-primary {\n",
+            "  syntax: \"<color>\";\n",
+            "  inherits: true;\n",
+            "  initial-value: #7C3AED;\n",
+            "}\n",
+        )));
+        assert!(css.contains(concat!(
+            "@property --molten-space-gap-md {\n",
+            "  syntax: \"<length>\";\n",
+            "  inherits: true;\n",
+            "  initial-value: 16px;\n",
+            "}\n",
+        )));
+        assert!(css.contains("@property --molten-space-64 {"));
+        assert_eq!(
+            css.matches("@property").count(),
+            colors::tokens().count() + 17 + spacing::semantic::ALL.len()
+        );
+    }
+
+    #[test]
+    fn test_less_variables() {
+        let less = less_variables("mb");
+        assert!(less.starts_with("@mb-forge-black: #0A0A0A;\n"));
+        assert!(less.contains("@mb-lair-terminal-background: #0F0F1A;\n"));
+        assert!(less.contains("@mb-glass-border: rgba(255, 255, 255, 0.06);\n"));
+    }
+
+    #[test]
+    fn test_identifier_sanitization() {
+        assert_eq!(
+            prefixed("My Brand!", "lair.surface.border-hover"),
+            "my-brand--lair-surface-border-hover"
+        );
+        assert_eq!(prefixed("", "molten.500"), "molten-500");
+        assert!(less_variables("Acme Co").contains("@acme-co-molten-500: #F97316;"));
+    }
+
+    #[test]
+    fn test_css_mix() {
+        assert_eq!(
+            css_mix("mb-primary", "mb-white", 20).as_deref(),
+            Some("color-mix(in oklch, var(--mb-primary) 20%, var(--mb-white))")
+        );
+        assert!(css_mix("a", "b", 100).is_some());
+    }
+
+    #[test]
+    fn test_css_mix_rejects_out_of_range() {
+        assert_eq!(css_mix("a", "b", 101), None);
+        assert_eq!(css_mix("a", "b", u8::MAX), None);
+    }
+
+    #[test]
+    fn test_to_gpl() {
+        let gpl = to_gpl("Molten");
+        assert!(gpl.starts_with("GIMP Palette\nName: Molten\nColumns: 11\n#\n"));
+        assert!(gpl.contains(" 10  10  10\tforge.black\n"));
+        assert!(gpl.contains("124  58 237\tlair.primary\n"));
+
+        let parsed = colors::from_gpl(&gpl).unwrap();
+        assert_eq!(parsed.len(), colors::TOKENS.len());
+    }
+
+    #[test]
+    fn test_to_json_is_valid() {
+        let value: serde_json::Value = serde_json::from_str(&to_json()).unwrap();
+        assert_eq!(value["semantic.success"], "#10B981");
+        assert_eq!(value["$version"], FORMAT_VERSION);
+        assert_eq!(FORMAT_VERSION, 1);
+    }
+
+    #[test]
+    fn test_coolors_url() {
+        let url = coolors_url();
+        assert!(url.contains("f97316"));
+        assert_eq!(url.matches('-').count(), CORE_PALETTE.len() - 1);
+        assert_eq!(hex_list().len(), CORE_PALETTE.len());
+        assert!(hex_list()
+            .iter()
+            .all(|hex| hex.len() == 6 && !hex.contains('#')));
+    }
+
+    #[test]
+    fn test_typography_css() {
+        let css = typography_css();
+        assert!(css.starts_with(".text-display {\n"));
+        let body = css.split(".text-body {\n").nth(1).unwrap();
+        let body = &body[..body.find('}').unwrap()];
+        assert!(body.contains("  font-size: 16px;\n"));
+        assert!(body.contains("  font-weight: 400;\n"));
+        assert!(css.contains(".text-label {\n"));
+        assert!(css.contains(".text-code {\n"));
+    }
+
+    #[test]
+    fn test_cheatsheet_hyperlinks() {
+        let linked = cheatsheet(TerminalCaps::TrueColor, true);
+        assert!(linked.contains(
+            "\x1b]8;;https://molten.dev/brand/tokens#molten-500\x1b\\molten.500\x1b]8;;\x1b\\"
+        ));
+        assert!(linked.contains("\x1b[38;2;249;115;22m██\x1b[0m "));
+
+        let plain = cheatsheet(TerminalCaps::NoColor, false);
+        assert!(!plain.contains('\x1b'));
+        assert_eq!(plain.lines().count(), colors::TOKENS.len());
+        let width = colors::tokens().map(|(name, _)| name.len()).max().unwrap();
+        assert!(plain
+            .lines()
+            .all(|line| line.as_bytes()[width] == b' ' && line.as_bytes()[width + 1] != b' '));
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"M"), "TQ==");
+        assert_eq!(base64(b"Ma"), "TWE=");
+        assert_eq!(base64(b"Man"), "TWFu");
+        assert_eq!(base64(b"0 0 0\0"), "MCAwIDAA");
+    }
+
+    #[test]
+    fn test_macos_terminal() {
+        let profile = macos_terminal("Goblin <Dark>");
+        assert!(profile.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        for key in [
+            "BackgroundColor",
+            "TextColor",
+            "CursorColor",
+            "ANSIBlackColor",
+            "ANSIBrightWhiteColor",
+        ] {
+            assert!(profile.contains(&format!("\t<key>{key}</key>\n\t<data>")));
+        }
+        assert!(profile.contains(&ns_color(lair::terminal::BACKGROUND)));
+        assert!(profile.contains("<string>Goblin &lt;Dark></string>"));
+        assert!(profile.ends_with("</dict>\n</plist>\n"));
+    }
+
+    #[test]
+    fn test_dotfiles_bundle() {
+        let bundle = dotfiles_bundle();
+        let paths: Vec<&str> = bundle.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "alacritty/themes/molten.toml",
+                "kitty/themes/molten.conf",
+                "tmux/molten.conf",
+                "zellij/themes/molten.kdl",
+                "starship/molten-palette.toml",
+                "delta/molten.gitconfig",
+            ]
+        );
+        assert!(bundle.iter().all(|(_, contents)| contents.ends_with('\n')));
+    }
+
+    #[test]
+    fn test_terminal_themes() {
+        let alacritty = alacritty_theme();
+        assert!(alacritty.contains("[colors.normal]\nblack = \"#1A1A2E\"\n"));
+        assert!(alacritty.contains("[colors.bright]\n"));
+        assert_eq!(alacritty.matches(" = \"#").count(), 21);
+
+        let kitty = kitty_theme();
+        assert!(kitty.contains("color1 #EF4444\n"));
+        assert!(kitty.contains("color15 #E4E4E7\n"));
+
+        assert!(starship_palette().contains("error = \"#EF4444\"\n"));
+        assert!(delta_config().contains("\tline-numbers-plus-style = \"#10B981\"\n"));
+    }
+
+    #[test]
+    fn test_css_with_schemes() {
+        let css = css_with_schemes("mb");
+        let (dark, light) = css
+            .split_once("@media (prefers-color-scheme: light) {")
+            .unwrap();
+        assert!(dark.starts_with(":root {\n"));
+        assert!(dark.contains("  --mb-surface-base: #0A0A0A;\n"));
+        assert!(light.contains("\n    --mb-surface-base: #FFFFFF;\n"));
+        assert!(!light.contains("--mb-molten-500"));
+        assert!(css.ends_with("  }\n}\n"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_oh_my_posh() {
+        let theme = oh_my_posh("molten");
+        let json = serde_json::to_string(&theme).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, theme);
+
+        let segments = theme["blocks"][0]["segments"].as_array().unwrap();
+        assert!(segments
+            .iter()
+            .any(|segment| segment["background"] == colors::molten::PRIMARY.hex()));
+        assert!(segments
+            .iter()
+            .all(|segment| segment["foreground"].is_string() && segment["background"].is_string()));
+        assert_eq!(
+            theme["console_title_template"],
+            "molten \u{b7} {{ .Folder }}"
+        );
+    }
+
+    #[test]
+    fn test_zellij_theme() {
+        let kdl = zellij_theme("goblin");
+        let lines: Vec<&str> = kdl.lines().collect();
+        assert_eq!(lines.len(), 15);
+        assert_eq!(lines[..2], ["themes {", "    goblin {"]);
+        assert_eq!(lines[13..], ["    }", "}"]);
+        assert_eq!(lines[2], "        fg 228 228 231");
+        assert_eq!(lines[12], "        orange 249 115 22");
+        assert!(lines[2..13]
+            .iter()
+            .all(|line| line.split(' ').filter(|s| !s.is_empty()).count() == 4));
+    }
+
+    #[test]
+    fn test_css_variables_layered() {
+        let css = css_variables_layered("base.tokens", "mb");
+        assert!(css.starts_with("@layer base.tokens {\n  :root {\n"));
+        assert!(css.contains("    --mb-molten-500: #F97316;\n"));
+        assert!(css.ends_with("  }\n}\n"));
+        assert_eq!(css.lines().count(), css_variables("mb").lines().count() + 2);
+    }
+}
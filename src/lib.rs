@@ -13,6 +13,7 @@
 //!
 //! // Get the primary Molten Orange
 //! let primary = colors::molten::PRIMARY;
+//! # #[cfg(feature = "std")]
 //! println!("Molten Orange: {}", primary.hex());
 //!
 //! // Get product-specific colors (Lair/Goblin theme)
@@ -38,27 +39,121 @@
 //!
 //! ## Features
 //!
-//! - `serde` - Enable serialization/deserialization of color types
+//! - `std` (default) - Enable everything that allocates or needs floating
+//!   point math from `std`
+//! - `serde` - Enable serialization/deserialization of color, spacing, and
+//!   typography types, and the oh-my-posh theme export
+//! - `terminal` - Enable `color::query_terminal_background`, which asks the
+//!   terminal for its background color (implies `std`)
+//!
+//! ## `no_std`
+//!
+//! With `default-features = false` the crate is `#![no_std]`. The color
+//! types, every `const` palette, channel access, parsing, [`Color::mix`],
+//! [`Color::blend`] and the `Display` impls remain available, along with
+//! `write_*` methods that target any [`core::fmt::Write`] sink. The
+//! following need `std`:
+//!
+//! - Methods returning `String` or `Vec` (`hex`, `css`, `CellStyle::to_ansi`, ...)
+//! - Color science that needs `f32` math (HSL, Lab, contrast, distance)
+//! - [`ColorRamp`] and the `colors::gradients` module
+//! - The `export` and `theme` modules
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 #![deny(clippy::all)]
 #![warn(clippy::pedantic)]
 
-mod color;
+pub mod color;
 pub mod colors;
+#[cfg(feature = "std")]
+pub mod export;
 pub mod products;
 pub mod semantic;
 pub mod spacing;
+#[cfg(feature = "std")]
+pub mod theme;
 pub mod typography;
 
-pub use color::{Color, Rgb, Rgba};
+pub use color::{BlendMode, Color, Easing, HexCase, ParseColorError, Rgb, Rgba};
+#[cfg(feature = "std")]
+pub use color::{ColorDelta, ColorRamp};
+pub use colors::{resolve, ResolveError};
+#[cfg(feature = "std")]
+pub use theme::Theme;
+
+/// Print a line in a semantic or token color, honoring `NO_COLOR`.
+///
+/// The first argument is a semantic kind (`error`, `warning`, `success`,
+/// `info`) or a token name as a string literal (`"lair.primary"`); the rest
+/// is passed to [`format!`]. Coloring is done by
+/// [`colors::paint_named`] with [`TerminalCaps::from_env`](color::TerminalCaps::from_env).
+/// Requires `std`.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::molten_println;
+///
+/// molten_println!(error, "build failed");
+/// molten_println!(success, "built {} crates", 3);
+/// molten_println!("lair.primary", "goblin spawned");
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! molten_println {
+    ($name:ident, $($arg:tt)+) => {
+        $crate::molten_println!(::core::stringify!($name), $($arg)+)
+    };
+    ($name:expr, $($arg:tt)+) => {
+        ::std::println!(
+            "{}",
+            $crate::colors::paint_named(
+                $name,
+                &::std::format!($($arg)+),
+                $crate::color::TerminalCaps::from_env(),
+            )
+        )
+    };
+}
 
 /// Re-export all color modules for convenience.
+///
+/// The prelude also carries the export helpers and the enums used by the
+/// color manipulation methods, so a single glob import covers common tasks.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::prelude::*;
+///
+/// let glow = molten::PRIMARY.blend(&lair::PRIMARY, BlendMode::Screen);
+/// let error = SemanticKind::Error.shade(Shade::Dark);
+///
+/// assert_eq!(Product::Lair.primary(), lair::PRIMARY);
+/// assert_eq!(error, ERROR_DARK);
+/// assert_ne!(glow, molten::PRIMARY);
+///
+/// // The export helpers need `std`.
+/// # #[cfg(feature = "std")]
+/// # {
+/// let css = css_variables("brand");
+/// assert!(css.contains("--brand-molten-500"));
+/// assert!(tailwind_colors().contains("lair"));
+/// assert!(to_json().contains("molten.500"));
+/// # }
+/// ```
 pub mod prelude {
-    pub use crate::color::{Color, Rgb, Rgba};
+    #[cfg(feature = "std")]
+    pub use crate::color::ColorRamp;
+    pub use crate::color::{BlendMode, Color, Easing, HexCase, ParseColorError, Rgb, Rgba};
     pub use crate::colors::*;
+    #[cfg(feature = "std")]
+    pub use crate::export::{css_variables, tailwind_colors, to_json};
     pub use crate::products::*;
     pub use crate::semantic::*;
+    #[cfg(feature = "std")]
+    pub use crate::theme::Theme;
 }
 
 /// Brand metadata.
@@ -74,12 +169,284 @@ pub mod brand {
 
     /// The GitHub organization.
     pub const GITHUB: &str = "https://github.com/moltenlabs";
+
+    pub use crate::colors::Token;
+
+    /// The brand metadata as a single value, e.g. for an about box.
+    ///
+    /// Start from [`info`] and override fields with the `with_*` builders.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::brand;
+    ///
+    /// let about = brand::info().with_tagline("Cooking since 2024");
+    /// assert_eq!(about.company, "Molten Labs");
+    /// assert_eq!(about.tagline, "Cooking since 2024");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    pub struct Brand {
+        /// The company name.
+        pub company: &'static str,
+        /// The primary tagline.
+        pub tagline: &'static str,
+        /// The website URL.
+        pub website: &'static str,
+        /// The GitHub organization URL.
+        pub github: &'static str,
+    }
+
+    impl Brand {
+        /// Replace the company name.
+        #[must_use]
+        pub const fn with_company(mut self, company: &'static str) -> Self {
+            self.company = company;
+            self
+        }
+
+        /// Replace the tagline.
+        #[must_use]
+        pub const fn with_tagline(mut self, tagline: &'static str) -> Self {
+            self.tagline = tagline;
+            self
+        }
+
+        /// Replace the website URL.
+        #[must_use]
+        pub const fn with_website(mut self, website: &'static str) -> Self {
+            self.website = website;
+            self
+        }
+
+        /// Replace the GitHub organization URL.
+        #[must_use]
+        pub const fn with_github(mut self, github: &'static str) -> Self {
+            self.github = github;
+            self
+        }
+    }
+
+    impl Default for Brand {
+        fn default() -> Self {
+            info()
+        }
+    }
+
+    /// The Molten Labs brand metadata.
+    #[must_use]
+    pub const fn info() -> Brand {
+        Brand {
+            company: COMPANY,
+            tagline: TAGLINE,
+            website: WEBSITE,
+            github: GITHUB,
+        }
+    }
+
+    use crate::color::Color;
+    use crate::colors::{forge, molten, text};
+    use crate::products::{hearth, lair};
+    use crate::semantic;
+
+    /// A brand color with guidance on where to use it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+    pub struct Swatch {
+        /// Token name, as used by [`Token::name`].
+        pub name: &'static str,
+        /// The color.
+        pub color: Color,
+        /// Intended usage.
+        pub usage: &'static str,
+    }
+
+    const SWATCHES: [Swatch; 13] = [
+        swatch(
+            "forge.black",
+            forge::BLACK,
+            "App backgrounds and dark surfaces",
+        ),
+        swatch(
+            "forge.steel",
+            forge::STEEL,
+            "Secondary text, borders and dividers",
+        ),
+        swatch(
+            "forge.white",
+            forge::WHITE,
+            "Primary text on dark backgrounds",
+        ),
+        swatch(
+            "forge.ember",
+            forge::EMBER,
+            "Accents and alerts that need attention",
+        ),
+        swatch("forge.iron", forge::IRON, "Links and interactive elements"),
+        swatch(
+            "molten.500",
+            molten::PRIMARY,
+            "CTAs and energy: primary buttons, highlights, focus",
+        ),
+        swatch(
+            "lair.primary",
+            lair::PRIMARY,
+            "Lair brand accents and the goblin glow",
+        ),
+        swatch(
+            "hearth.primary",
+            hearth::PRIMARY,
+            "Hearth brand accents and editorial links",
+        ),
+        swatch(
+            "text.muted",
+            text::MUTED,
+            "Hints, placeholders and disabled text",
+        ),
+        swatch(
+            "semantic.success",
+            semantic::SUCCESS,
+            "Success states and positive outcomes",
+        ),
+        swatch(
+            "semantic.warning",
+            semantic::WARNING,
+            "Warning states where caution is needed",
+        ),
+        swatch(
+            "semantic.error",
+            semantic::ERROR,
+            "Error states and destructive actions",
+        ),
+        swatch(
+            "semantic.info",
+            semantic::INFO,
+            "Informational messages and neutral status",
+        ),
+    ];
+
+    const fn swatch(name: &'static str, color: Color, usage: &'static str) -> Swatch {
+        Swatch { name, color, usage }
+    }
+
+    /// The core brand colors with usage notes, for docs and help panels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::brand::swatches;
+    ///
+    /// let primary = swatches().iter().find(|s| s.name == "molten.500").unwrap();
+    /// assert!(primary.usage.contains("CTAs"));
+    /// ```
+    #[must_use]
+    pub fn swatches() -> &'static [Swatch] {
+        &SWATCHES
+    }
+
+    /// Visual effect tokens shared by the web and native UIs.
+    ///
+    /// Lengths are in pixels, angles in degrees and opacities in percent.
+    pub mod effects {
+        use crate::color::Color;
+        use crate::products::lair;
+
+        /// Angle of the molten flame gradient (top-left to bottom-right).
+        pub const GRADIENT_ANGLE: u16 = 135;
+
+        /// Default glow blur radius.
+        pub const GLOW_RADIUS: u16 = 24;
+
+        /// Default glow opacity.
+        pub const GLOW_OPACITY: u8 = 40;
+
+        /// Default drop shadow blur radius.
+        pub const SHADOW_RADIUS: u16 = 16;
+
+        /// Default drop shadow opacity.
+        pub const SHADOW_OPACITY: u8 = 20;
+
+        /// A soft glow around an element.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use molten_brand::{brand::effects::GlowEffect, products::lair};
+        ///
+        /// assert_eq!(GlowEffect::default().color(), lair::goblin::GLOW);
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct GlowEffect {
+            /// The glow color, without opacity applied.
+            pub color: Color,
+            /// Blur radius in pixels.
+            pub radius: u16,
+            /// Opacity in percent (0 - 100).
+            pub opacity: u8,
+        }
+
+        impl GlowEffect {
+            /// Lair's goblin glow.
+            pub const GOBLIN: Self = Self {
+                color: lair::goblin::PRIMARY,
+                radius: GLOW_RADIUS,
+                opacity: GLOW_OPACITY,
+            };
+
+            /// The glow color with its opacity applied.
+            #[must_use]
+            pub const fn color(&self) -> Color {
+                self.color.at_opacity(self.opacity)
+            }
+
+            /// The glow as a CSS `box-shadow` value, e.g.
+            /// `0 0 24px rgba(124, 58, 237, 0.4)`.
+            #[cfg(feature = "std")]
+            #[must_use]
+            pub fn box_shadow(&self) -> String {
+                format!("0 0 {}px {}", self.radius, self.color().to_rgba().css())
+            }
+        }
+
+        impl Default for GlowEffect {
+            fn default() -> Self {
+                Self::GOBLIN
+            }
+        }
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_paint_named() {
+        use color::TerminalCaps;
+
+        let line = colors::paint_named("error", "build failed", TerminalCaps::TrueColor);
+        assert_eq!(line, "\x1b[38;2;239;68;68mbuild failed\x1b[0m");
+        assert!(colors::paint_named("ERROR", "x", TerminalCaps::Ansi256).starts_with("\x1b[38;5;"));
+        assert_eq!(
+            colors::paint_named("lair.primary", "x", TerminalCaps::TrueColor),
+            "\x1b[38;2;124;58;237mx\x1b[0m"
+        );
+        assert_eq!(
+            colors::paint_named("nope", "x", TerminalCaps::TrueColor),
+            "x"
+        );
+        assert_eq!(
+            colors::paint_named("error", "x", TerminalCaps::NoColor),
+            "x"
+        );
+        assert_eq!(
+            colors::paint_named("semantic.success", "ok", TerminalCaps::TrueColor),
+            "\x1b[38;2;16;185;129mok\x1b[0m"
+        );
+    }
+
     #[test]
     fn test_color_hex() {
         assert_eq!(colors::forge::BLACK.hex(), "#0A0A0A");
@@ -98,4 +465,96 @@ mod tests {
         assert_eq!(semantic::SUCCESS.hex(), "#10B981");
         assert_eq!(semantic::ERROR.hex(), "#EF4444");
     }
+
+    #[test]
+    fn test_brand_info() {
+        let info = brand::info();
+        assert_eq!(info.tagline, "Let them cook");
+        assert_eq!(
+            info,
+            brand::Brand {
+                company: brand::COMPANY,
+                tagline: brand::TAGLINE,
+                website: brand::WEBSITE,
+                github: brand::GITHUB,
+            }
+        );
+        assert_eq!(info.with_company("Forge").company, "Forge");
+        assert_eq!(brand::Brand::default(), info);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_brand_info_serializes() {
+        let json = serde_json::to_string(&brand::info()).unwrap();
+        assert!(json.contains("\"tagline\":\"Let them cook\""));
+    }
+
+    #[test]
+    fn test_swatches() {
+        let primary = brand::swatches()
+            .iter()
+            .find(|swatch| swatch.color == colors::molten::PRIMARY && swatch.name == "molten.500")
+            .unwrap();
+        assert!(primary.usage.contains("CTAs"));
+        for swatch in brand::swatches() {
+            assert_eq!(
+                brand::Token::from_name(swatch.name).map(brand::Token::color),
+                Some(swatch.color)
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_glow() {
+        let glow = brand::effects::GlowEffect::default();
+        assert_eq!(glow.color(), products::lair::goblin::GLOW);
+        assert_eq!(glow.radius, brand::effects::GLOW_RADIUS);
+        assert!(glow.box_shadow().starts_with("0 0 24px "));
+    }
+}
+
+#[cfg(test)]
+mod core_tests {
+    use super::*;
+    use core::fmt::{self, Write};
+
+    /// A fixed-capacity `fmt::Write` sink that needs no allocator.
+    struct Buf {
+        bytes: [u8; 64],
+        len: usize,
+    }
+
+    impl Write for Buf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    impl Buf {
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_core_api() {
+        let mut buf = Buf {
+            bytes: [0; 64],
+            len: 0,
+        };
+        colors::molten::PRIMARY.write_hex(&mut buf).unwrap();
+        assert_eq!(buf.as_str(), "#F97316");
+
+        let gray = Color::BLACK.mix(&Color::WHITE, 0.5);
+        assert_eq!(gray, Color::rgb(128, 128, 128));
+        assert_eq!(Color::try_from_hex("#7C3AED"), Ok(products::lair::PRIMARY));
+        assert_eq!(spacing::units(4), 16);
+    }
 }
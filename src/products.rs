@@ -225,6 +225,136 @@ pub mod alloy {
     }
 }
 
+/// A Molten Labs product.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::products::{lair, Product};
+///
+/// assert_eq!(Product::Lair.primary(), lair::PRIMARY);
+/// assert_eq!(Product::from_name("HEARTH"), Some(Product::Hearth));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Product {
+    /// Lair - Terminal for Goblins.
+    Lair,
+    /// Hearth - Content Marketing Platform.
+    Hearth,
+    /// Alloy - Design System.
+    #[default]
+    Alloy,
+}
+
+impl Product {
+    /// All products, in declaration order.
+    pub const ALL: [Self; 3] = [Self::Lair, Self::Hearth, Self::Alloy];
+
+    /// Look up a product by name (case-insensitive).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|product| product.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Product name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Lair => lair::meta::NAME,
+            Self::Hearth => hearth::meta::NAME,
+            Self::Alloy => alloy::meta::NAME,
+        }
+    }
+
+    /// Product tagline.
+    #[must_use]
+    pub const fn tagline(self) -> &'static str {
+        match self {
+            Self::Lair => lair::meta::TAGLINE,
+            Self::Hearth => hearth::meta::TAGLINE,
+            Self::Alloy => alloy::meta::TAGLINE,
+        }
+    }
+
+    /// Product description.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Lair => lair::meta::DESCRIPTION,
+            Self::Hearth => hearth::meta::DESCRIPTION,
+            Self::Alloy => alloy::meta::DESCRIPTION,
+        }
+    }
+
+    /// Primary color.
+    #[must_use]
+    pub const fn primary(self) -> Color {
+        match self {
+            Self::Lair => lair::PRIMARY,
+            Self::Hearth => hearth::PRIMARY,
+            Self::Alloy => alloy::PRIMARY,
+        }
+    }
+
+    /// Secondary color.
+    #[must_use]
+    pub const fn secondary(self) -> Color {
+        match self {
+            Self::Lair => lair::SECONDARY,
+            Self::Hearth => hearth::SECONDARY,
+            Self::Alloy => alloy::SECONDARY,
+        }
+    }
+
+    /// Accent color.
+    #[must_use]
+    pub const fn accent(self) -> Color {
+        match self {
+            Self::Lair => lair::ACCENT,
+            Self::Hearth => hearth::ACCENT,
+            Self::Alloy => alloy::ACCENT,
+        }
+    }
+
+    /// The accent color adjusted to read clearly on `surface`.
+    ///
+    /// Uses [`Color::adjust_for_contrast`] with a minimum ratio of 3.0, the
+    /// WCAG AA level for UI components and large text, so the hue and
+    /// saturation stay on brand and only the lightness moves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::products::{lair, Product};
+    ///
+    /// let accent = Product::Lair.accent_for_surface(lair::terminal::BACKGROUND);
+    /// assert!(accent.contrast_ratio(&lair::terminal::BACKGROUND) >= 3.0);
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn accent_for_surface(self, surface: Color) -> Color {
+        const MIN_CONTRAST: f32 = 3.0;
+        self.accent().adjust_for_contrast(&surface, MIN_CONTRAST)
+    }
+
+    /// Get the canonical opaque app background for this product.
+    ///
+    /// Lair maps to [`lair::terminal::BACKGROUND`] (Cave Dark), Hearth to
+    /// [`hearth::content::BACKGROUND`], and Alloy to
+    /// [`alloy::system::SURFACE`].
+    #[must_use]
+    pub const fn background(self) -> Color {
+        match self {
+            Self::Lair => lair::terminal::BACKGROUND,
+            Self::Hearth => hearth::content::BACKGROUND,
+            Self::Alloy => alloy::system::SURFACE,
+        }
+    }
+}
+
 /// Get product tokens by name.
 ///
 /// # Example
@@ -237,19 +367,162 @@ pub mod alloy {
 /// ```
 #[must_use]
 pub fn get_product_primary(product: &str) -> Color {
-    match product.to_lowercase().as_str() {
-        "lair" => lair::PRIMARY,
-        "hearth" => hearth::PRIMARY,
-        _ => alloy::PRIMARY, // Default to Alloy (includes "alloy")
-    }
+    // Default to Alloy
+    Product::from_name(product).unwrap_or_default().primary()
 }
 
 /// Get product tagline by name.
 #[must_use]
 pub fn get_product_tagline(product: &str) -> &'static str {
-    match product.to_lowercase().as_str() {
-        "lair" => lair::meta::TAGLINE,
-        "hearth" => hearth::meta::TAGLINE,
-        _ => alloy::meta::TAGLINE, // Default to Alloy (includes "alloy")
+    // Default to Alloy
+    Product::from_name(product).unwrap_or_default().tagline()
+}
+
+/// Check the design invariants shared by every product palette.
+///
+/// Requires the `std` feature. The rules are:
+///
+/// - each product's body text meets WCAG AA (4.5:1) against its background;
+/// - the translucent border tokens (Lair surface borders, Alloy glass
+///   borders) really are semi-transparent;
+/// - product primaries are perceptually distinct (Lab distance of at
+///   least 10).
+///
+/// # Errors
+///
+/// Returns one message per violation, naming the tokens involved.
+///
+/// # Example
+///
+/// ```rust
+/// assert!(molten_brand::products::validate().is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn validate() -> Result<(), Vec<String>> {
+    const MIN_TEXT_CONTRAST: f32 = 4.5;
+    const MIN_PRIMARY_DISTANCE: f32 = 10.0;
+
+    let text_pairs = [
+        (
+            "lair.terminal.foreground",
+            lair::terminal::FOREGROUND,
+            "lair.terminal.background",
+            lair::terminal::BACKGROUND,
+        ),
+        (
+            "hearth.editorial.text",
+            hearth::editorial::TEXT,
+            "hearth.content.background",
+            hearth::content::BACKGROUND,
+        ),
+        (
+            "text.primary",
+            crate::colors::text::PRIMARY,
+            "alloy.system.surface",
+            alloy::system::SURFACE,
+        ),
+    ];
+    let borders = [
+        ("lair.surface.border", lair::surface::BORDER),
+        ("lair.surface.border-hover", lair::surface::BORDER_HOVER),
+        ("alloy.glass.border", alloy::glass::BORDER),
+        ("alloy.glass.border-hover", alloy::glass::BORDER_HOVER),
+    ];
+
+    let mut violations = Vec::new();
+    for (fg_name, fg, bg_name, bg) in text_pairs {
+        let ratio = fg.contrast_ratio(&bg);
+        if ratio < MIN_TEXT_CONTRAST {
+            violations.push(format!(
+                "{fg_name} on {bg_name}: contrast {ratio:.2} is below {MIN_TEXT_CONTRAST}"
+            ));
+        }
+    }
+    for (name, border) in borders {
+        if border.a() == u8::MAX {
+            violations.push(format!("{name}: border is opaque"));
+        }
+    }
+    for (i, a) in Product::ALL.iter().enumerate() {
+        for b in &Product::ALL[i + 1..] {
+            let distance = a.primary().distance(&b.primary());
+            if distance < MIN_PRIMARY_DISTANCE {
+                violations.push(format!(
+                    "{}.primary and {}.primary are too similar (distance {distance:.1})",
+                    a.name().to_lowercase(),
+                    b.name().to_lowercase()
+                ));
+            }
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(violations)
+    }
+}
+
+/// A full 50 - 950 tonal scale around a product's primary color.
+///
+/// Generated with [`ScaleBuilder`](crate::colors::ScaleBuilder) defaults, so
+/// index 5 (step 500) is the product primary and the other steps follow
+/// [`SCALE_STEPS`](crate::colors::SCALE_STEPS): purple for Lair, blue for
+/// Hearth and orange for Alloy.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::products::{lair, scale_for, Product};
+///
+/// assert_eq!(scale_for(Product::Lair)[5], lair::PRIMARY);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn scale_for(product: Product) -> [Color; 11] {
+    crate::colors::ScaleBuilder::new(product.primary())
+        .build()
+        .steps()
+        .map(|(_, color)| color)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accent_for_surface() {
+        let dark = lair::terminal::BACKGROUND;
+        assert!(lair::ACCENT.contrast_ratio(&dark) < 3.0);
+        let accent = Product::Lair.accent_for_surface(dark);
+        assert!(accent.contrast_ratio(&dark) >= 3.0);
+        let drift = (accent.to_hsl().0 - lair::ACCENT.to_hsl().0).abs();
+        assert!(drift < 5.0, "hue drifted {drift}");
+
+        let light = Color::rgb(250, 250, 250);
+        assert_eq!(Product::Alloy.accent_for_surface(light), alloy::ACCENT);
+    }
+
+    #[test]
+    fn test_background() {
+        assert_eq!(Product::Lair.background(), lair::terminal::BACKGROUND);
+        assert_eq!(Product::Hearth.background(), hearth::content::BACKGROUND);
+        assert_eq!(Product::Alloy.background(), alloy::system::SURFACE);
+        assert!(Product::ALL.iter().all(|p| p.background().a() == 255));
+    }
+
+    #[test]
+    fn test_current_tokens_validate() {
+        assert_eq!(validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_scale_for() {
+        for product in Product::ALL {
+            let scale = scale_for(product);
+            assert!(scale[5].distance(&product.primary()) < 1.0);
+            assert!(scale[0].luminance() > scale[10].luminance());
+        }
+        assert_ne!(scale_for(Product::Lair), scale_for(Product::Hearth));
     }
 }
@@ -87,8 +87,172 @@ pub mod agent {
     pub const PAUSED: Color = Color::rgb(167, 139, 250); // #A78BFA
 }
 
+/// The kinds of semantic feedback a color can convey.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SemanticKind {
+    /// Positive outcomes.
+    Success,
+    /// Caution needed.
+    Warning,
+    /// Problems or failures.
+    Error,
+    /// Neutral information.
+    Info,
+}
+
+impl SemanticKind {
+    /// All semantic kinds, in declaration order.
+    pub const ALL: [Self; 4] = [Self::Success, Self::Warning, Self::Error, Self::Info];
+
+    /// The lowercase name of this kind.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::Info => "info",
+        }
+    }
+
+    /// Look up a kind by name (case-insensitive).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The base color for this kind.
+    #[must_use]
+    pub const fn color(self) -> Color {
+        self.shade(Shade::Base)
+    }
+
+    /// The color for this kind at the given shade.
+    #[must_use]
+    pub const fn shade(self, shade: Shade) -> Color {
+        match (self, shade) {
+            (Self::Success, Shade::Base) => SUCCESS,
+            (Self::Success, Shade::Light) => SUCCESS_LIGHT,
+            (Self::Success, Shade::Dark) => SUCCESS_DARK,
+            (Self::Warning, Shade::Base) => WARNING,
+            (Self::Warning, Shade::Light) => WARNING_LIGHT,
+            (Self::Warning, Shade::Dark) => WARNING_DARK,
+            (Self::Error, Shade::Base) => ERROR,
+            (Self::Error, Shade::Light) => ERROR_LIGHT,
+            (Self::Error, Shade::Dark) => ERROR_DARK,
+            (Self::Info, Shade::Base) => INFO,
+            (Self::Info, Shade::Light) => INFO_LIGHT,
+            (Self::Info, Shade::Dark) => INFO_DARK,
+        }
+    }
+}
+
+/// The variant of a semantic color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shade {
+    /// The standard color.
+    #[default]
+    Base,
+    /// The light variant (backgrounds, badges).
+    Light,
+    /// The dark variant (hover, emphasis).
+    Dark,
+}
+
+impl Shade {
+    /// All shades, in declaration order.
+    pub const ALL: [Self; 3] = [Self::Base, Self::Light, Self::Dark];
+}
+
+/// Every semantic color with its shade variants.
+///
+/// Yields `(kind, shade, color)` for each [`SemanticKind`] in declaration
+/// order, and for each kind every [`Shade`] in declaration order (base,
+/// light, dark).
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::semantic::all_with_variants;
+///
+/// assert_eq!(all_with_variants().count(), 12);
+/// ```
+pub fn all_with_variants() -> impl Iterator<Item = (SemanticKind, Shade, Color)> {
+    SemanticKind::ALL.into_iter().flat_map(|kind| {
+        Shade::ALL
+            .into_iter()
+            .map(move |shade| (kind, shade, kind.shade(shade)))
+    })
+}
+
+/// The shade of `kind` with the highest contrast against `surface`.
+///
+/// Compares the base, light and dark variants with
+/// [`Color::contrast_ratio`]; ties go to the earlier shade in [`Shade::ALL`].
+/// On light surfaces this is usually the dark variant, on dark surfaces the
+/// light one.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::semantic::{self, on_surface, SemanticKind};
+/// use molten_brand::Color;
+///
+/// assert_eq!(on_surface(SemanticKind::Success, Color::WHITE), semantic::SUCCESS_DARK);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn on_surface(kind: SemanticKind, surface: Color) -> Color {
+    let mut best = kind.color();
+    let mut best_ratio = best.contrast_ratio(&surface);
+    for shade in [Shade::Light, Shade::Dark] {
+        let color = kind.shade(shade);
+        let ratio = color.contrast_ratio(&surface);
+        if ratio > best_ratio {
+            best = color;
+            best_ratio = ratio;
+        }
+    }
+    best
+}
+
+/// A `(fill, text)` pair for a status chip of `kind` on a dark or light
+/// surface.
+///
+/// On dark surfaces the fill is the dark variant mixed 60% towards black, a
+/// muted tone that sits quietly on the page, and the text is the bright
+/// light variant. On light surfaces the fill is the saturated base color and
+/// the text is [`best_text_color`](crate::colors::text::best_text_color) for
+/// it, which is dark for every kind. Every pair meets WCAG AA (4.5).
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::semantic::{pair, SemanticKind, ERROR_LIGHT};
+///
+/// let (fill, text) = pair(SemanticKind::Error, true);
+/// assert_eq!(text, ERROR_LIGHT);
+/// assert!(text.contrast_ratio(&fill) >= 4.5);
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn pair(kind: SemanticKind, on_dark: bool) -> (Color, Color) {
+    const DARK_FILL_MIX: f32 = 0.6;
+    if on_dark {
+        let fill = kind.shade(Shade::Dark).mix(&Color::BLACK, DARK_FILL_MIX);
+        (fill, kind.shade(Shade::Light))
+    } else {
+        let fill = kind.color();
+        (fill, crate::colors::text::best_text_color(fill))
+    }
+}
+
 /// Semantic color struct for use in themes.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SemanticColors {
     /// Success color.
@@ -127,12 +291,118 @@ impl SemanticColors {
     /// Get color by semantic name.
     #[must_use]
     pub fn get(&self, name: &str) -> Option<Color> {
-        match name.to_lowercase().as_str() {
-            "success" => Some(self.success),
-            "warning" => Some(self.warning),
-            "error" => Some(self.error),
-            "info" => Some(self.info),
-            _ => None,
+        SemanticKind::from_name(name).map(|kind| self.kind(kind))
+    }
+
+    /// Get color by semantic kind.
+    #[must_use]
+    pub const fn kind(&self, kind: SemanticKind) -> Color {
+        match kind {
+            SemanticKind::Success => self.success,
+            SemanticKind::Warning => self.warning,
+            SemanticKind::Error => self.error,
+            SemanticKind::Info => self.info,
+        }
+    }
+}
+
+/// Map a health, progress or confidence ratio onto the semantic colors.
+///
+/// `ratio` is clamped to 0.0 - 1.0 (NaN counts as 0.0). The scale has three
+/// anchors: [`ERROR`] at 0.0, [`WARNING`] at 0.5 and [`SUCCESS`] at 1.0.
+/// Values in between are interpolated in sRGB between the two nearest
+/// anchors with [`Color::mix`].
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::semantic::{self, for_ratio};
+///
+/// assert_eq!(for_ratio(0.0), semantic::ERROR);
+/// assert_eq!(for_ratio(0.5), semantic::WARNING);
+/// assert_eq!(for_ratio(1.5), semantic::SUCCESS);
+/// ```
+#[must_use]
+pub fn for_ratio(ratio: f32) -> Color {
+    let ratio = if ratio.is_nan() {
+        0.0
+    } else {
+        ratio.clamp(0.0, 1.0)
+    };
+    if ratio < 0.5 {
+        ERROR.mix(&WARNING, ratio * 2.0)
+    } else {
+        WARNING.mix(&SUCCESS, (ratio - 0.5) * 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_ratio_anchors() {
+        assert_eq!(for_ratio(0.0), ERROR);
+        assert_eq!(for_ratio(0.5), WARNING);
+        assert_eq!(for_ratio(1.0), SUCCESS);
+        assert_eq!(for_ratio(-1.0), ERROR);
+        assert_eq!(for_ratio(2.0), SUCCESS);
+        assert_eq!(for_ratio(f32::NAN), ERROR);
+    }
+
+    #[test]
+    fn test_for_ratio_interpolates() {
+        let low = for_ratio(0.25);
+        assert_eq!(low, ERROR.mix(&WARNING, 0.5));
+        assert!(low.g() > ERROR.g() && low.g() < WARNING.g());
+
+        let high = for_ratio(0.75);
+        assert!(high.g() > WARNING.g() && high.r() < WARNING.r());
+    }
+
+    #[test]
+    fn test_all_with_variants() {
+        assert_eq!(all_with_variants().count(), 12);
+        for kind in SemanticKind::ALL {
+            assert_eq!(
+                all_with_variants().filter(|(k, _, _)| *k == kind).count(),
+                3
+            );
+        }
+        assert!(all_with_variants()
+            .any(|entry| entry == (SemanticKind::Success, Shade::Light, SUCCESS_LIGHT)));
+        assert_eq!(
+            all_with_variants().next(),
+            Some((SemanticKind::Success, Shade::Base, SUCCESS))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_pair() {
+        use crate::colors::text;
+
+        for kind in SemanticKind::ALL {
+            for on_dark in [true, false] {
+                let (fill, label) = pair(kind, on_dark);
+                let ratio = label.contrast_ratio(&fill);
+                assert!(ratio >= 4.5, "{kind:?} on_dark={on_dark}: {ratio}");
+            }
+            let (dark_fill, dark_text) = pair(kind, true);
+            assert!(dark_fill.luminance() < dark_text.luminance());
+            assert_eq!(pair(kind, false), (kind.color(), text::INVERSE));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_on_surface() {
+        let near_white = Color::rgb(250, 250, 250);
+        let near_black = Color::rgb(10, 10, 10);
+        for kind in SemanticKind::ALL {
+            assert_eq!(on_surface(kind, near_white), kind.shade(Shade::Dark));
+            let on_dark = on_surface(kind, near_black);
+            assert!(on_dark == kind.shade(Shade::Base) || on_dark == kind.shade(Shade::Light));
         }
     }
 }
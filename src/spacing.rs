@@ -77,6 +77,95 @@ pub mod semantic {
 
     /// Page margin.
     pub const PAGE: u16 = scale::S16;
+
+    /// Every alias with its kebab-case name, as used by
+    /// [`semantic_by_name`](super::semantic_by_name).
+    pub const ALL: [(&str, u16); 9] = [
+        ("inline", INLINE),
+        ("component-sm", COMPONENT_SM),
+        ("component-md", COMPONENT_MD),
+        ("component-lg", COMPONENT_LG),
+        ("gap-sm", GAP_SM),
+        ("gap-md", GAP_MD),
+        ("gap-lg", GAP_LG),
+        ("section", SECTION),
+        ("page", PAGE),
+    ];
+}
+
+/// The spacing scale as a value, for (de)serializing alongside other tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpacingScale {
+    /// Base spacing unit in pixels.
+    pub base: u16,
+    /// Step 0 (0px).
+    pub s0: u16,
+    /// Step 1 (4px).
+    pub s1: u16,
+    /// Step 2 (8px).
+    pub s2: u16,
+    /// Step 3 (12px).
+    pub s3: u16,
+    /// Step 4 (16px).
+    pub s4: u16,
+    /// Step 5 (20px).
+    pub s5: u16,
+    /// Step 6 (24px).
+    pub s6: u16,
+    /// Step 8 (32px).
+    pub s8: u16,
+    /// Step 10 (40px).
+    pub s10: u16,
+    /// Step 12 (48px).
+    pub s12: u16,
+    /// Step 16 (64px).
+    pub s16: u16,
+    /// Step 20 (80px).
+    pub s20: u16,
+    /// Step 24 (96px).
+    pub s24: u16,
+    /// Step 32 (128px).
+    pub s32: u16,
+    /// Step 40 (160px).
+    pub s40: u16,
+    /// Step 48 (192px).
+    pub s48: u16,
+    /// Step 64 (256px).
+    pub s64: u16,
+}
+
+impl Default for SpacingScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpacingScale {
+    /// Create a spacing scale with the brand values.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            base: BASE,
+            s0: scale::S0,
+            s1: scale::S1,
+            s2: scale::S2,
+            s3: scale::S3,
+            s4: scale::S4,
+            s5: scale::S5,
+            s6: scale::S6,
+            s8: scale::S8,
+            s10: scale::S10,
+            s12: scale::S12,
+            s16: scale::S16,
+            s20: scale::S20,
+            s24: scale::S24,
+            s32: scale::S32,
+            s40: scale::S40,
+            s48: scale::S48,
+            s64: scale::S64,
+        }
+    }
 }
 
 /// Get spacing value by scale index.
@@ -112,10 +201,103 @@ pub const fn get(index: u16) -> u16 {
     }
 }
 
+/// A CSS length unit for [`css`].
+///
+/// Relative units carry the root font size in pixels they are computed
+/// against (16 in browsers by default). A root of 0 is meaningless and is
+/// treated as that 16px default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CssUnit {
+    /// Pixels.
+    #[default]
+    Px,
+    /// `rem`, relative to the given root font size in pixels.
+    Rem(u16),
+    /// `em`, relative to the given font size in pixels.
+    Em(u16),
+}
+
+/// Format the spacing value at a scale index as a CSS length.
+///
+/// Uses the same lookup as [`get`]. A relative unit with a root of 0 falls
+/// back to the browser default of 16px.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::spacing::{css, CssUnit};
+///
+/// assert_eq!(css(4, CssUnit::Px), "16px");
+/// assert_eq!(css(4, CssUnit::Rem(16)), "1rem");
+/// assert_eq!(css(1, CssUnit::Em(16)), "0.25em");
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn css(index: u16, unit: CssUnit) -> String {
+    const DEFAULT_ROOT: u16 = 16;
+
+    let px = get(index);
+    let relative = |root: u16| {
+        let root = if root == 0 { DEFAULT_ROOT } else { root };
+        f32::from(px) / f32::from(root)
+    };
+    match unit {
+        CssUnit::Px => format!("{px}px"),
+        CssUnit::Rem(root) => format!("{}rem", relative(root)),
+        CssUnit::Em(root) => format!("{}em", relative(root)),
+    }
+}
+
 /// Convert spacing units to pixels.
+///
+/// Saturates at `u16::MAX` for `n` above 16383; use [`checked_units`] to
+/// detect that case.
 #[must_use]
 pub const fn units(n: u16) -> u16 {
-    n * BASE
+    n.saturating_mul(BASE)
+}
+
+/// Convert spacing units to pixels, or `None` if the result overflows
+/// `u16` (`n` above 16383).
+#[must_use]
+pub const fn checked_units(n: u16) -> Option<u16> {
+    n.checked_mul(BASE)
+}
+
+/// Look up a [`semantic`] spacing alias by name, in pixels.
+///
+/// Matching ignores ASCII case and treats `_` like `-`, so `gap-md`,
+/// `GAP_MD` and `Gap_Md` all resolve to [`semantic::GAP_MD`].
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::spacing::semantic_by_name;
+///
+/// assert_eq!(semantic_by_name("gap-md"), Some(16));
+/// assert_eq!(semantic_by_name("COMPONENT_SM"), Some(8));
+/// assert_eq!(semantic_by_name("gutter"), None);
+/// ```
+#[must_use]
+pub fn semantic_by_name(name: &str) -> Option<u16> {
+    let normalize = |b: u8| {
+        if b == b'_' {
+            b'-'
+        } else {
+            b.to_ascii_lowercase()
+        }
+    };
+    semantic::ALL
+        .iter()
+        .find(|(alias, _)| {
+            alias.len() == name.len()
+                && alias
+                    .bytes()
+                    .zip(name.bytes())
+                    .all(|(a, b)| a == normalize(b))
+        })
+        .map(|&(_, px)| px)
 }
 
 #[cfg(test)]
@@ -135,4 +317,48 @@ mod tests {
         assert_eq!(units(4), 16);
         assert_eq!(units(10), 40);
     }
+
+    #[test]
+    fn test_units_overflow() {
+        assert_eq!(checked_units(16383), Some(65532));
+        assert_eq!(checked_units(16384), None);
+        assert_eq!(checked_units(u16::MAX), None);
+        assert_eq!(units(16383), 65532);
+        assert_eq!(units(16384), u16::MAX);
+    }
+
+    #[test]
+    fn test_semantic_by_name() {
+        assert_eq!(semantic_by_name("gap-md"), Some(16));
+        assert_eq!(semantic_by_name("Gap_MD"), Some(semantic::GAP_MD));
+        assert_eq!(semantic_by_name("page"), Some(64));
+        assert_eq!(semantic_by_name("gap-xl"), None);
+        assert_eq!(semantic_by_name(""), None);
+        for (name, px) in semantic::ALL {
+            assert_eq!(semantic_by_name(name), Some(px));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_spacing_scale_serde_round_trip() {
+        let scale = SpacingScale::new();
+        let json = serde_json::to_string(&scale).unwrap();
+        assert!(json.contains("\"s4\":16"));
+        let parsed: SpacingScale = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, scale);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_css() {
+        assert_eq!(css(4, CssUnit::Px), "16px");
+        assert_eq!(css(4, CssUnit::Rem(16)), "1rem");
+        assert_eq!(css(4, CssUnit::Rem(10)), "1.6rem");
+        assert_eq!(css(4, CssUnit::Em(16)), "1em");
+        assert_eq!(css(0, CssUnit::Rem(16)), "0rem");
+        // A zero root falls back to 16px rather than dividing by it.
+        assert_eq!(css(4, CssUnit::Rem(0)), "1rem");
+        assert_eq!(css(8, CssUnit::Em(0)), "2em");
+    }
 }
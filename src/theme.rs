@@ -0,0 +1,785 @@
+//! Runtime themes.
+//!
+//! A [`Theme`] bundles the tokens an application needs at runtime into a
+//! single value that can be passed around, customized, and animated.
+
+use std::fmt::{self, Write};
+
+use crate::color::Color;
+use crate::colors::{surface, text};
+use crate::export::{css_value, prefixed};
+use crate::products::{alloy, hearth, lair, Product};
+use crate::semantic::SemanticColors;
+use crate::spacing;
+
+/// A complete set of UI tokens for one product.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::{products::Product, Theme};
+///
+/// let theme = Theme::for_product(Product::Lair);
+/// assert_eq!(theme.primary.hex(), "#7C3AED");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    /// Theme name.
+    pub name: String,
+    /// Primary brand color.
+    pub primary: Color,
+    /// Secondary brand color.
+    pub secondary: Color,
+    /// Accent color.
+    pub accent: Color,
+    /// App background.
+    pub background: Color,
+    /// Raised surface (cards, panels).
+    pub surface: Color,
+    /// Primary text color.
+    pub foreground: Color,
+    /// Muted text color.
+    pub muted: Color,
+    /// Border color.
+    pub border: Color,
+    /// Semantic status colors.
+    pub semantic: SemanticColors,
+    /// Base spacing unit in pixels.
+    pub spacing_unit: u16,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::alloy()
+    }
+}
+
+impl Theme {
+    /// The theme for a product.
+    #[must_use]
+    pub fn for_product(product: Product) -> Self {
+        match product {
+            Product::Lair => Self::lair(),
+            Product::Hearth => Self::hearth(),
+            Product::Alloy => Self::alloy(),
+        }
+    }
+
+    /// The Lair theme (Goblin Purple on Cave Dark).
+    #[must_use]
+    pub fn lair() -> Self {
+        Self {
+            name: lair::meta::NAME.to_string(),
+            primary: lair::PRIMARY,
+            secondary: lair::SECONDARY,
+            accent: lair::ACCENT,
+            background: lair::terminal::BACKGROUND,
+            surface: lair::surface::RAISED,
+            foreground: lair::terminal::FOREGROUND,
+            muted: text::MUTED,
+            border: lair::surface::BORDER,
+            semantic: SemanticColors::new(),
+            spacing_unit: spacing::BASE,
+        }
+    }
+
+    /// The Hearth theme (Iron Blue editorial).
+    #[must_use]
+    pub fn hearth() -> Self {
+        Self {
+            name: hearth::meta::NAME.to_string(),
+            primary: hearth::PRIMARY,
+            secondary: hearth::SECONDARY,
+            accent: hearth::ACCENT,
+            background: hearth::content::BACKGROUND,
+            surface: hearth::content::CARD,
+            foreground: hearth::editorial::TEXT,
+            muted: hearth::editorial::SECONDARY,
+            border: hearth::content::BORDER,
+            semantic: SemanticColors::new(),
+            spacing_unit: spacing::BASE,
+        }
+    }
+
+    /// The Alloy theme (Molten Orange on Forge Black).
+    #[must_use]
+    pub fn alloy() -> Self {
+        Self {
+            name: alloy::meta::NAME.to_string(),
+            primary: alloy::PRIMARY,
+            secondary: alloy::SECONDARY,
+            accent: alloy::ACCENT,
+            background: surface::BASE,
+            surface: surface::RAISED,
+            foreground: text::PRIMARY,
+            muted: text::MUTED,
+            border: surface::OVERLAY,
+            semantic: SemanticColors::new(),
+            spacing_unit: spacing::BASE,
+        }
+    }
+
+    /// Interpolate every color towards `other` by `t` (0.0 - 1.0, clamped).
+    ///
+    /// Colors are blended with [`Color::mix`]. Non-color fields (`name`,
+    /// `spacing_unit`) switch to `other`'s value once `t >= 0.5`.
+    #[must_use]
+    pub fn lerp(&self, other: &Theme, t: f32) -> Theme {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: Color, b: Color| a.mix(&b, t);
+        let discrete = if t >= 0.5 { other } else { self };
+        Theme {
+            name: discrete.name.clone(),
+            primary: mix(self.primary, other.primary),
+            secondary: mix(self.secondary, other.secondary),
+            accent: mix(self.accent, other.accent),
+            background: mix(self.background, other.background),
+            surface: mix(self.surface, other.surface),
+            foreground: mix(self.foreground, other.foreground),
+            muted: mix(self.muted, other.muted),
+            border: mix(self.border, other.border),
+            semantic: SemanticColors {
+                success: mix(self.semantic.success, other.semantic.success),
+                warning: mix(self.semantic.warning, other.semantic.warning),
+                error: mix(self.semantic.error, other.semantic.error),
+                info: mix(self.semantic.info, other.semantic.info),
+            },
+            spacing_unit: discrete.spacing_unit,
+        }
+    }
+
+    /// Apply a single override by field name.
+    ///
+    /// Semantic colors are addressed as `semantic.success` and so on. Colors
+    /// accept anything [`Color::from_str`](std::str::FromStr) does (hex,
+    /// `rgb()`, `rgba()`), and `spacing_unit` takes an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThemeError`] if the key is unknown or the value does not
+    /// parse.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ThemeError> {
+        let invalid = || ThemeError::InvalidValue(key.to_string());
+        let slot = match key {
+            "name" => {
+                self.name = value.to_string();
+                return Ok(());
+            }
+            "spacing_unit" => {
+                self.spacing_unit = value.parse().map_err(|_| invalid())?;
+                return Ok(());
+            }
+            "primary" => &mut self.primary,
+            "secondary" => &mut self.secondary,
+            "accent" => &mut self.accent,
+            "background" => &mut self.background,
+            "surface" => &mut self.surface,
+            "foreground" => &mut self.foreground,
+            "muted" => &mut self.muted,
+            "border" => &mut self.border,
+            "semantic.success" => &mut self.semantic.success,
+            "semantic.warning" => &mut self.semantic.warning,
+            "semantic.error" => &mut self.semantic.error,
+            "semantic.info" => &mut self.semantic.info,
+            _ => return Err(ThemeError::UnknownKey(key.to_string())),
+        };
+        *slot = value.parse().map_err(|_| invalid())?;
+        Ok(())
+    }
+
+    /// Build a theme from TOML overrides on top of [`Theme::default`].
+    ///
+    /// The overrides can sit at the top level or under a `[theme]` table,
+    /// with semantic colors in `[semantic]` / `[theme.semantic]` (or dotted
+    /// `semantic.*` keys). Keys that are not theme fields and all other
+    /// tables are ignored, so the theme can live inside an application's
+    /// existing config file. Each override is applied with [`Theme::set`],
+    /// the same way the JSON loader applies them.
+    ///
+    /// Only the subset of TOML a theme needs is parsed: single-line
+    /// `key = value` pairs with basic (`\"` and `\\` escapes) or literal
+    /// (`'...'`) strings or integers, and `#` comments. Lines inside ignored
+    /// tables are skipped without parsing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Theme;
+    ///
+    /// let theme = Theme::from_toml(r##"
+    /// editor = "vim"
+    ///
+    /// [theme]
+    /// primary = '#7C3AED'
+    /// spacing_unit = 8
+    ///
+    /// [theme.semantic]
+    /// error = "#DC2626"
+    ///
+    /// [keys]
+    /// quit = ["q", "ctrl+c"]
+    /// "##).unwrap();
+    /// assert_eq!(theme.primary.hex(), "#7C3AED");
+    /// assert_eq!(theme.spacing_unit, 8);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ThemeError`] on a malformed line outside an ignored table,
+    /// or a theme value that does not parse.
+    pub fn from_toml(toml: &str) -> Result<Self, ThemeError> {
+        let mut theme = Self::default();
+        let mut table = Some(String::new());
+        for (index, line) in toml.lines().enumerate() {
+            let syntax = || ThemeError::Syntax(index + 1);
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name.strip_suffix(']').ok_or_else(syntax)?.trim();
+                table = matches!(name, "theme" | "semantic" | "theme.semantic")
+                    .then(|| format!("{name}."));
+                continue;
+            }
+            let Some(table) = &table else {
+                continue;
+            };
+            let (key, value) = line.split_once('=').ok_or_else(syntax)?;
+            let value = value.trim();
+            let value = if let Some(quoted) = value.strip_prefix('"') {
+                Some(unescape(quoted).ok_or_else(syntax)?)
+            } else if let Some(literal) = value.strip_prefix('\'') {
+                match literal.split_once('\'') {
+                    Some((literal, "")) => Some(literal.to_string()),
+                    _ => return Err(syntax()),
+                }
+            } else if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+                Some(value.to_string())
+            } else {
+                None
+            };
+            theme.merge(&format!("{table}{}", key.trim()), value.as_deref())?;
+        }
+        Ok(theme)
+    }
+
+    /// Apply one override read from a config document.
+    ///
+    /// A leading `theme.` is dropped and keys that are not theme fields are
+    /// skipped. `value` is `None` when the document holds something other
+    /// than a string or integer, which is an error for a theme field.
+    fn merge(&mut self, key: &str, value: Option<&str>) -> Result<(), ThemeError> {
+        let key = key.strip_prefix("theme.").unwrap_or(key);
+        let known = matches!(key, "name" | "spacing_unit")
+            || self.colors().iter().any(|(name, _)| *name == key);
+        match value {
+            _ if !known => Ok(()),
+            Some(value) => self.set(key, value),
+            None => Err(ThemeError::InvalidValue(key.to_string())),
+        }
+    }
+
+    /// List the WCAG AA contrast failures among this theme's own colors.
+    ///
+    /// `foreground` is checked as body text (4.5) against both `background`
+    /// and `surface`. `muted` is for hints and captions, so like the semantic
+    /// colors it is held to the large-text and UI threshold (3.0); semantic
+    /// colors are checked against `background` only. Each message names
+    /// the fields involved, so an app can warn about overrides that break
+    /// legibility. An empty list means the theme passes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::{Color, Theme};
+    ///
+    /// let mut theme = Theme::alloy();
+    /// theme.foreground = Color::rgb(40, 40, 40);
+    /// assert!(!theme.accessibility_issues().is_empty());
+    /// ```
+    #[must_use]
+    pub fn accessibility_issues(&self) -> Vec<String> {
+        const MIN_TEXT_CONTRAST: f32 = 4.5;
+        const MIN_UI_CONTRAST: f32 = 3.0;
+
+        let backgrounds = [("background", self.background), ("surface", self.surface)];
+        let mut checks = Vec::new();
+        for (bg_name, bg) in backgrounds {
+            checks.push((
+                "foreground",
+                self.foreground,
+                bg_name,
+                bg,
+                MIN_TEXT_CONTRAST,
+            ));
+            checks.push(("muted", self.muted, bg_name, bg, MIN_UI_CONTRAST));
+        }
+        for (fg_name, fg) in &self.colors()[8..] {
+            checks.push((fg_name, *fg, "background", self.background, MIN_UI_CONTRAST));
+        }
+
+        checks
+            .into_iter()
+            .filter_map(|(fg_name, fg, bg_name, bg, min)| {
+                let ratio = fg.contrast_ratio(&bg);
+                (ratio < min)
+                    .then(|| format!("{fg_name} on {bg_name}: contrast {ratio:.2} is below {min}"))
+            })
+            .collect()
+    }
+
+    /// Every color field, keyed as [`Theme::set`] names it.
+    fn colors(&self) -> [(&'static str, Color); 12] {
+        [
+            ("primary", self.primary),
+            ("secondary", self.secondary),
+            ("accent", self.accent),
+            ("background", self.background),
+            ("surface", self.surface),
+            ("foreground", self.foreground),
+            ("muted", self.muted),
+            ("border", self.border),
+            ("semantic.success", self.semantic.success),
+            ("semantic.warning", self.semantic.warning),
+            ("semantic.error", self.semantic.error),
+            ("semantic.info", self.semantic.info),
+        ]
+    }
+
+    /// Serialize the theme to JSON. Requires the `serde` feature.
+    ///
+    /// The document has the same shape as [`Theme::to_toml`]: `name`,
+    /// `spacing_unit` and hex colors at the top level, with the semantic
+    /// colors in a nested `semantic` object. [`Theme::from_json`] reads the
+    /// output back unchanged.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        use serde_json::{Map, Value};
+
+        let mut root = Map::new();
+        root.insert("name".to_string(), Value::from(self.name.as_str()));
+        root.insert("spacing_unit".to_string(), Value::from(self.spacing_unit));
+        let colors = self.colors();
+        let (base, semantic) = colors.split_at(8);
+        for (key, color) in base {
+            root.insert((*key).to_string(), Value::from(hex(*color)));
+        }
+        let semantic = semantic
+            .iter()
+            .map(|(key, color)| {
+                let key = key.trim_start_matches("semantic.");
+                (key.to_string(), Value::from(hex(*color)))
+            })
+            .collect();
+        root.insert("semantic".to_string(), Value::Object(semantic));
+        serde_json::to_string_pretty(&root).unwrap_or_default()
+    }
+
+    /// Build a theme from JSON overrides on top of [`Theme::default`].
+    /// Requires the `serde` feature.
+    ///
+    /// The input is a JSON object of string or integer values, keyed like
+    /// [`Theme::from_toml`]: the overrides sit at the top level or in a
+    /// `theme` object, and semantic colors go in a nested `semantic` object
+    /// or under dotted `semantic.*` keys. Keys that are not theme fields are
+    /// ignored. Each override is applied with [`Theme::set`], so partial
+    /// documents are fine.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Theme;
+    ///
+    /// let theme = Theme::from_json(r##"{"primary": "#7C3AED", "spacing_unit": 8}"##).unwrap();
+    /// assert_eq!(theme.primary.hex(), "#7C3AED");
+    /// assert_eq!(theme.spacing_unit, 8);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThemeError::Json`] if the input is not a JSON object, and
+    /// [`ThemeError::InvalidValue`] for a theme value that does not parse.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, ThemeError> {
+        use serde_json::{Map, Value};
+
+        fn apply(
+            theme: &mut Theme,
+            table: &str,
+            object: Map<String, Value>,
+        ) -> Result<(), ThemeError> {
+            for (key, value) in object {
+                let key = format!("{table}{key}");
+                let value = match value {
+                    Value::String(value) => Some(value),
+                    Value::Number(value) if value.is_u64() => Some(value.to_string()),
+                    Value::Object(inner) => {
+                        apply(theme, &format!("{key}."), inner)?;
+                        continue;
+                    }
+                    _ => None,
+                };
+                theme.merge(&key, value.as_deref())?;
+            }
+            Ok(())
+        }
+
+        let Value::Object(object) =
+            serde_json::from_str(json).map_err(|err| ThemeError::Json(err.to_string()))?
+        else {
+            return Err(ThemeError::Json("expected an object".to_string()));
+        };
+        let mut theme = Self::default();
+        apply(&mut theme, "", object)?;
+        Ok(theme)
+    }
+
+    /// Serialize the theme to the TOML accepted by [`Theme::from_toml`].
+    ///
+    /// Opaque colors are written as `#RRGGBB` and translucent ones as
+    /// `#RRGGBBAA`, and `"` and `\` in the name are escaped, so the theme
+    /// round-trips exactly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Theme;
+    ///
+    /// let theme = Theme::lair();
+    /// assert_eq!(Theme::from_toml(&theme.to_toml()), Ok(theme));
+    /// ```
+    #[must_use]
+    pub fn to_toml(&self) -> String {
+        let mut out = format!(
+            "name = \"{}\"\nspacing_unit = {}\n",
+            escape(&self.name),
+            self.spacing_unit
+        );
+        let colors = self.colors();
+        let (base, semantic) = colors.split_at(8);
+        for (key, color) in base {
+            let _ = writeln!(out, "{key} = \"{}\"", hex(*color));
+        }
+        out.push_str("\n[semantic]\n");
+        for (key, color) in semantic {
+            let key = key.trim_start_matches("semantic.");
+            let _ = writeln!(out, "{key} = \"{}\"", hex(*color));
+        }
+        out
+    }
+
+    /// Generate a `:root` block of CSS custom properties for this theme.
+    ///
+    /// Variables are named like [`export::css_variables`](crate::export::css_variables)
+    /// (`--<prefix>-primary`, `--<prefix>-semantic-success`, ...), plus
+    /// `--<prefix>-spacing-unit` in pixels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::Theme;
+    ///
+    /// let css = Theme::alloy().to_css("mb");
+    /// assert!(css.contains("  --mb-primary: #F97316;\n"));
+    /// ```
+    #[must_use]
+    pub fn to_css(&self, prefix: &str) -> String {
+        let mut out = String::from(":root {\n");
+        for (key, color) in self.colors() {
+            let _ = writeln!(out, "  --{}: {};", prefixed(prefix, key), css_value(color));
+        }
+        let _ = writeln!(
+            out,
+            "  --{}: {}px;",
+            prefixed(prefix, "spacing-unit"),
+            self.spacing_unit
+        );
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// A color as `#RRGGBB`, or `#RRGGBBAA` if it carries alpha.
+fn hex(color: Color) -> String {
+    match color {
+        Color::Rgb(rgb) => rgb.hex(),
+        Color::Rgba(_) => color.hex_rgba(),
+    }
+}
+
+/// Escape `"` and `\` for a TOML basic string.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Read the rest of a TOML basic string after its opening quote.
+///
+/// Returns `None` unless the closing quote ends the value.
+fn unescape(quoted: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return chars.as_str().is_empty().then_some(out),
+            '\\' => match chars.next()? {
+                c @ ('"' | '\\') => out.push(c),
+                _ => return None,
+            },
+            c => out.push(c),
+        }
+    }
+    None
+}
+
+/// Strip a trailing `#` comment, ignoring `#` inside quoted strings.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (c, quote) {
+            _ if escaped => escaped = false,
+            ('\\', Some('"')) => escaped = true,
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(open)) if c == open => quote = None,
+            ('#', None) => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// An error applying theme overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemeError {
+    /// A line could not be parsed (1-based line number).
+    Syntax(usize),
+    /// The key does not name a theme field.
+    UnknownKey(String),
+    /// The value for this key is not a valid color or integer.
+    InvalidValue(String),
+    /// The input is not a JSON object (the parser's message).
+    Json(String),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(line) => write!(f, "syntax error on line {line}"),
+            Self::UnknownKey(key) => write!(f, "unknown theme key `{key}`"),
+            Self::InvalidValue(key) => write!(f, "invalid value for `{key}`"),
+            Self::Json(err) => write!(f, "invalid theme JSON: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp_endpoints() {
+        let lair = Theme::lair();
+        let hearth = Theme::hearth();
+        assert_eq!(lair.lerp(&hearth, 0.0), lair);
+        assert_eq!(lair.lerp(&hearth, 1.0), hearth);
+    }
+
+    #[test]
+    fn test_lerp_discrete_fields() {
+        let lair = Theme::lair();
+        let hearth = Theme::hearth();
+        assert_eq!(lair.lerp(&hearth, 0.49).name, "Lair");
+        assert_eq!(lair.lerp(&hearth, 0.5).name, "Hearth");
+    }
+
+    #[test]
+    fn test_from_toml_partial() {
+        let theme = Theme::from_toml("primary = \"#7C3AED\" # goblin purple\n").unwrap();
+        assert_eq!(theme.primary, lair::PRIMARY);
+        assert_eq!(
+            Theme {
+                primary: Theme::default().primary,
+                ..theme
+            },
+            Theme::default()
+        );
+    }
+
+    #[test]
+    fn test_from_toml_tables_and_errors() {
+        let theme = Theme::from_toml("spacing_unit = 8\n[semantic]\nerror = \"#000\"").unwrap();
+        assert_eq!(theme.spacing_unit, 8);
+        assert_eq!(theme.semantic.error, Color::BLACK);
+
+        assert_eq!(
+            Theme::from_toml("primary = 8"),
+            Err(ThemeError::InvalidValue("primary".to_string()))
+        );
+        assert_eq!(
+            Theme::from_toml("primary = true"),
+            Err(ThemeError::InvalidValue("primary".to_string()))
+        );
+        assert_eq!(Theme::from_toml("\nprimary"), Err(ThemeError::Syntax(2)));
+        assert_eq!(
+            Theme::from_toml("name = \"a\\q\""),
+            Err(ThemeError::Syntax(1))
+        );
+    }
+
+    #[test]
+    fn test_from_toml_theme_table() {
+        let theme = Theme::from_toml(
+            "[theme]\nprimary = \"#7C3AED\"\n[theme.semantic]\nerror = \"#000\"\n",
+        )
+        .unwrap();
+        assert_eq!(theme.primary, lair::PRIMARY);
+        assert_eq!(theme.semantic.error, Color::BLACK);
+        assert_eq!(
+            Theme::from_toml("theme.spacing_unit = 6").map(|theme| theme.spacing_unit),
+            Ok(6)
+        );
+    }
+
+    #[test]
+    fn test_from_toml_ignores_unrelated_config() {
+        let config = r##"
+title = "my app"
+verbose = true
+glow = "#fff"
+
+[theme]
+accent = "#000"
+
+[keys]
+quit = ["q", "ctrl+c"]
+primary = { nested = 1 }
+  multi-line and "unparsed" text
+"##;
+        let theme = Theme::from_toml(config).unwrap();
+        assert_eq!(
+            theme,
+            Theme {
+                accent: Color::BLACK,
+                ..Theme::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_toml_literal_strings() {
+        let theme =
+            Theme::from_toml("name = 'C:\\themes \"x\" #1' # literal\nprimary = '#000'").unwrap();
+        assert_eq!(theme.name, r#"C:\themes "x" #1"#);
+        assert_eq!(theme.primary, Color::BLACK);
+        assert_eq!(Theme::from_toml("name = 'open"), Err(ThemeError::Syntax(1)));
+        assert_eq!(
+            Theme::from_toml("name = 'a' 'b'"),
+            Err(ThemeError::Syntax(1))
+        );
+    }
+
+    #[test]
+    fn test_toml_name_escapes() {
+        let mut theme = Theme::lair();
+        theme.name = r#"Lair "Night" \ #2"#.to_string();
+        let toml = theme.to_toml();
+        assert!(toml.starts_with(r#"name = "Lair \"Night\" \\ #2""#));
+        assert_eq!(Theme::from_toml(&toml), Ok(theme));
+    }
+
+    fn custom_theme() -> Theme {
+        let mut theme = Theme::hearth();
+        theme.name = "Custom".to_string();
+        theme.set("accent", "rgba(249, 115, 22, 0.4)").unwrap();
+        theme.set("semantic.info", "#123456").unwrap();
+        theme.spacing_unit = 6;
+        theme
+    }
+
+    #[test]
+    fn test_to_toml_round_trip() {
+        for theme in [
+            Theme::lair(),
+            Theme::hearth(),
+            Theme::alloy(),
+            custom_theme(),
+        ] {
+            assert_eq!(Theme::from_toml(&theme.to_toml()), Ok(theme));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_json_round_trip() {
+        let theme = custom_theme();
+        let json = theme.to_json();
+        assert!(json.contains("\"info\": \"#123456\""));
+        assert_eq!(Theme::from_json(&json), Ok(theme));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json_partial() {
+        let theme = Theme::from_json(r##"{"primary":"#7C3AED"}"##).unwrap();
+        assert_eq!(
+            theme,
+            Theme {
+                primary: lair::PRIMARY,
+                ..Theme::default()
+            }
+        );
+        let theme = Theme::from_json(
+            r##"{"spacing_unit": 8, "semantic": {"error": "#000"}, "semantic.info": "#fff"}"##,
+        )
+        .unwrap();
+        assert_eq!(theme.spacing_unit, 8);
+        assert_eq!(theme.semantic.error, Color::BLACK);
+        assert_eq!(theme.semantic.info, Color::WHITE);
+
+        assert_eq!(Theme::from_json("{}"), Ok(Theme::default()));
+        assert!(matches!(Theme::from_json("[]"), Err(ThemeError::Json(_))));
+        assert!(matches!(Theme::from_json("{"), Err(ThemeError::Json(_))));
+        assert_eq!(
+            Theme::from_json(r#"{"primary": true}"#),
+            Err(ThemeError::InvalidValue("primary".to_string()))
+        );
+        let embedded = Theme::from_json(
+            r##"{"glow": "#fff", "keys": {"quit": ["q"]}, "theme": {"semantic": {"info": "#000"}}}"##,
+        )
+        .unwrap();
+        assert_eq!(embedded.semantic.info, Color::BLACK);
+        assert_eq!(embedded.primary, Theme::default().primary);
+    }
+
+    #[test]
+    fn test_to_css() {
+        let css = custom_theme().to_css("");
+        assert!(css.starts_with(":root {\n"));
+        assert!(css.contains("  --semantic-info: #123456;\n"));
+        assert!(css.contains("  --accent: rgba(249, 115, 22, 0.40);\n"));
+        assert!(css.ends_with("  --spacing-unit: 6px;\n}\n"));
+    }
+
+    #[test]
+    fn test_accessibility_issues() {
+        for theme in [Theme::lair(), Theme::hearth(), Theme::alloy()] {
+            assert!(theme.accessibility_issues().is_empty(), "{}", theme.name);
+        }
+
+        let mut theme = Theme::alloy();
+        theme.foreground = Color::BLACK;
+        theme.surface = Color::rgb(12, 12, 14);
+        let issues = theme.accessibility_issues();
+        assert!(issues
+            .iter()
+            .any(|issue| issue.starts_with("foreground on surface: contrast 1.")));
+        assert!(issues
+            .iter()
+            .all(|issue| issue.starts_with("foreground on")));
+    }
+}
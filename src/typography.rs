@@ -99,7 +99,151 @@ pub mod letter_spacing {
     pub const WIDEST: f32 = 0.1;
 }
 
+/// OpenType feature tags for [`TextStyle::font_features`].
+///
+/// Geist and Fraunces both support these.
+pub mod features {
+    /// Tabular (fixed-width) numerals.
+    pub const TABULAR_NUMS: &str = "tnum";
+    /// Slashed zero.
+    pub const SLASHED_ZERO: &str = "zero";
+    /// Stylistic set 1.
+    pub const STYLISTIC_SET_1: &str = "ss01";
+    /// Contextual alternates.
+    pub const CONTEXTUAL_ALTERNATES: &str = "calt";
+}
+
+/// Variable font axis tags, for `font-variation-settings`.
+pub mod axes {
+    /// Weight (Geist, Fraunces).
+    pub const WEIGHT: &str = "wght";
+    /// Optical size (Fraunces).
+    pub const OPTICAL_SIZE: &str = "opsz";
+    /// Softness (Fraunces).
+    pub const SOFTNESS: &str = "SOFT";
+    /// Wonky glyph alternates (Fraunces).
+    pub const WONK: &str = "WONK";
+}
+
+/// The font size scale as a value, for (de)serializing alongside other tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SizeScale {
+    /// 12px - Tiny.
+    pub tiny: u16,
+    /// 14px - Small.
+    pub small: u16,
+    /// 16px - Base.
+    pub base: u16,
+    /// 18px - Large body.
+    pub large: u16,
+    /// 20px - Lead paragraph.
+    pub lead: u16,
+    /// 22px - H4.
+    pub h4: u16,
+    /// 24px - H3.
+    pub h3: u16,
+    /// 28px - H2.
+    pub h2: u16,
+    /// 36px - H1.
+    pub h1: u16,
+    /// 48px - Display.
+    pub display: u16,
+    /// 60px - Display large.
+    pub display_lg: u16,
+    /// 72px - Display extra large.
+    pub display_xl: u16,
+}
+
+impl Default for SizeScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SizeScale {
+    /// Create the scale with the brand values.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            tiny: sizes::TINY,
+            small: sizes::SMALL,
+            base: sizes::BASE,
+            large: sizes::LARGE,
+            lead: sizes::LEAD,
+            h4: sizes::H4,
+            h3: sizes::H3,
+            h2: sizes::H2,
+            h1: sizes::H1,
+            display: sizes::DISPLAY,
+            display_lg: sizes::DISPLAY_LG,
+            display_xl: sizes::DISPLAY_XL,
+        }
+    }
+}
+
+/// The font weight scale as a value, for (de)serializing alongside other tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightScale {
+    /// 100 - Thin.
+    pub thin: u16,
+    /// 200 - Extra Light.
+    pub extra_light: u16,
+    /// 300 - Light.
+    pub light: u16,
+    /// 400 - Regular.
+    pub regular: u16,
+    /// 500 - Medium.
+    pub medium: u16,
+    /// 600 - Semi Bold.
+    pub semi_bold: u16,
+    /// 700 - Bold.
+    pub bold: u16,
+    /// 800 - Extra Bold.
+    pub extra_bold: u16,
+    /// 900 - Black.
+    pub black: u16,
+}
+
+impl Default for WeightScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WeightScale {
+    /// Create the scale with the brand values.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            thin: weights::THIN,
+            extra_light: weights::EXTRA_LIGHT,
+            light: weights::LIGHT,
+            regular: weights::REGULAR,
+            medium: weights::MEDIUM,
+            semi_bold: weights::SEMI_BOLD,
+            bold: weights::BOLD,
+            extra_bold: weights::EXTRA_BOLD,
+            black: weights::BLACK,
+        }
+    }
+}
+
 /// Typography preset for a text style.
+///
+/// Build custom styles with [`TextStyle::new`] and
+/// [`TextStyle::with_features`], or from a preset with struct update syntax.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::typography::{families, features, TextStyle};
+///
+/// let stat = TextStyle::new(families::MONO, 32, 600, 1.1, 0.0)
+///     .with_features(&[features::TABULAR_NUMS]);
+/// assert_eq!(stat.font_features, ["tnum"]);
+/// ```
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextStyle {
@@ -113,11 +257,107 @@ pub struct TextStyle {
     pub line_height: f32,
     /// Letter spacing in em.
     pub letter_spacing: f32,
+    /// OpenType feature tags to enable (see [`features`]), empty for the
+    /// font's defaults.
+    ///
+    /// Serialized but not read back: a deserialized style always has no
+    /// features, so a serde round-trip drops them.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    pub font_features: &'static [&'static str],
+}
+
+impl TextStyle {
+    /// A style with the font's default features.
+    #[must_use]
+    pub const fn new(
+        family: &'static str,
+        size: u16,
+        weight: u16,
+        line_height: f32,
+        letter_spacing: f32,
+    ) -> Self {
+        Self {
+            family,
+            size,
+            weight,
+            line_height,
+            letter_spacing,
+            font_features: &[],
+        }
+    }
+
+    /// A copy that enables the given OpenType feature tags (see
+    /// [`features`]), replacing any already set.
+    #[must_use]
+    pub const fn with_features(self, font_features: &'static [&'static str]) -> Self {
+        Self {
+            font_features,
+            ..self
+        }
+    }
+
+    /// A copy with the font size multiplied by `factor`, rounded to the
+    /// nearest pixel and at least 1px.
+    ///
+    /// Line height and letter spacing are relative to the size, so they are
+    /// unchanged and scale along with it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::typography::presets;
+    ///
+    /// assert_eq!(presets::BODY.scaled(0.875).size, 14);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn scaled(&self, factor: f32) -> TextStyle {
+        let size = (f32::from(self.size) * factor + 0.5) as u16;
+        TextStyle {
+            size: size.max(1),
+            ..self.clone()
+        }
+    }
+
+    /// Render the style as CSS declarations, one per line.
+    ///
+    /// A `font-feature-settings` line is added when [`TextStyle::font_features`]
+    /// is not empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::typography::presets;
+    ///
+    /// assert!(presets::BODY.to_css().contains("font-size: 16px;"));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_css(&self) -> String {
+        use std::fmt::Write as _;
+
+        let mut css = format!(
+            "font-family: {};\nfont-size: {}px;\nfont-weight: {};\nline-height: {};\nletter-spacing: {}em;\n",
+            self.family, self.size, self.weight, self.line_height, self.letter_spacing
+        );
+        for (i, tag) in self.font_features.iter().enumerate() {
+            let lead = if i == 0 {
+                "font-feature-settings: "
+            } else {
+                ", "
+            };
+            let _ = write!(css, "{lead}\"{tag}\"");
+        }
+        if !self.font_features.is_empty() {
+            css.push_str(";\n");
+        }
+        css
+    }
 }
 
 /// Pre-defined text style presets.
 pub mod presets {
-    use super::{families, letter_spacing, line_heights, sizes, weights, TextStyle};
+    use super::{families, features, letter_spacing, line_heights, sizes, weights, TextStyle};
 
     /// Display heading style.
     pub const DISPLAY: TextStyle = TextStyle {
@@ -126,6 +366,7 @@ pub mod presets {
         weight: weights::BOLD,
         line_height: line_heights::TIGHT,
         letter_spacing: letter_spacing::TIGHTER,
+        font_features: &[],
     };
 
     /// H1 heading style.
@@ -135,6 +376,7 @@ pub mod presets {
         weight: weights::BOLD,
         line_height: line_heights::TIGHT,
         letter_spacing: letter_spacing::TIGHT,
+        font_features: &[],
     };
 
     /// H2 heading style.
@@ -144,6 +386,7 @@ pub mod presets {
         weight: weights::SEMI_BOLD,
         line_height: line_heights::SNUG,
         letter_spacing: letter_spacing::TIGHT,
+        font_features: &[],
     };
 
     /// H3 heading style.
@@ -153,6 +396,7 @@ pub mod presets {
         weight: weights::SEMI_BOLD,
         line_height: line_heights::SNUG,
         letter_spacing: letter_spacing::NORMAL,
+        font_features: &[],
     };
 
     /// Body text style.
@@ -162,6 +406,7 @@ pub mod presets {
         weight: weights::REGULAR,
         line_height: line_heights::NORMAL,
         letter_spacing: letter_spacing::NORMAL,
+        font_features: &[],
     };
 
     /// Small text style.
@@ -171,6 +416,7 @@ pub mod presets {
         weight: weights::REGULAR,
         line_height: line_heights::NORMAL,
         letter_spacing: letter_spacing::NORMAL,
+        font_features: &[],
     };
 
     /// Code/monospace style.
@@ -180,8 +426,13 @@ pub mod presets {
         weight: weights::REGULAR,
         line_height: line_heights::RELAXED,
         letter_spacing: letter_spacing::NORMAL,
+        font_features: &[],
     };
 
+    /// Body text with tabular numerals, for tables, stats and counters
+    /// whose digits must line up.
+    pub const NUMERIC: TextStyle = BODY.with_features(&[features::TABULAR_NUMS]);
+
     /// Label style (small caps effect).
     pub const LABEL: TextStyle = TextStyle {
         family: families::SANS,
@@ -189,5 +440,155 @@ pub mod presets {
         weight: weights::MEDIUM,
         line_height: line_heights::NORMAL,
         letter_spacing: letter_spacing::WIDE,
+        font_features: &[],
     };
+
+    /// Every preset keyed by its lowercase name, largest first.
+    const ALL: &[(&str, TextStyle)] = &[
+        ("display", DISPLAY),
+        ("h1", H1),
+        ("h2", H2),
+        ("h3", H3),
+        ("body", BODY),
+        ("numeric", NUMERIC),
+        ("small", SMALL),
+        ("code", CODE),
+        ("label", LABEL),
+    ];
+
+    /// Iterate over every preset with its lowercase name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use molten_brand::typography::presets;
+    ///
+    /// let body = presets::all().find(|(name, _)| *name == "body");
+    /// assert_eq!(body.map(|(_, style)| style.size), Some(16));
+    /// ```
+    pub fn all() -> impl Iterator<Item = (&'static str, &'static TextStyle)> {
+        ALL.iter().map(|(name, style)| (*name, style))
+    }
+}
+
+/// Estimate the terminal cells one character of `preset` occupies.
+///
+/// Returns `(columns, rows)`: how many cells wide one glyph is and how many
+/// cells tall one line is, each rounded to the nearest whole cell and at
+/// least 1. This assumes a monospace face with a glyph advance of 0.6 em and
+/// terminal cells twice as tall as they are wide, which holds for common
+/// terminal fonts. Multiply by the text's character and line counts to size
+/// a block.
+///
+/// # Example
+///
+/// ```rust
+/// use molten_brand::typography::{presets, terminal_metrics};
+///
+/// assert_eq!(terminal_metrics(&presets::CODE, 8), (1, 1));
+/// assert_eq!(terminal_metrics(&presets::DISPLAY, 8), (4, 3));
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn terminal_metrics(preset: &TextStyle, cell_width_px: u16) -> (u16, u16) {
+    let cell_width = u32::from(cell_width_px.max(1));
+    let advance_tenths = u32::from(preset.size) * 6;
+    let columns = (advance_tenths + cell_width * 5) / (cell_width * 10);
+    let line_px = f32::from(preset.size) * preset.line_height;
+    let rows = (line_px / (2.0 * f32::from(cell_width_px.max(1))) + 0.5) as u16;
+    (columns.max(1) as u16, rows.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_scales_serde_round_trip() {
+        let json = serde_json::to_string(&SizeScale::new()).unwrap();
+        assert!(json.contains("\"base\":16"));
+        assert_eq!(
+            serde_json::from_str::<SizeScale>(&json).unwrap(),
+            SizeScale::new()
+        );
+
+        let json = serde_json::to_string(&WeightScale::new()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<WeightScale>(&json).unwrap(),
+            WeightScale::new()
+        );
+    }
+
+    #[test]
+    fn test_scale_defaults() {
+        assert_eq!(SizeScale::default().h1, sizes::H1);
+        assert_eq!(WeightScale::default().bold, weights::BOLD);
+    }
+
+    #[test]
+    fn test_presets_all() {
+        assert!(presets::all()
+            .map(|(name, _)| name)
+            .eq(["display", "h1", "h2", "h3", "body", "numeric", "small", "code", "label"]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_css() {
+        assert_eq!(
+            presets::LABEL.to_css(),
+            format!(
+                "font-family: {};\nfont-size: 12px;\nfont-weight: 500;\nline-height: 1.5;\nletter-spacing: 0.025em;\n",
+                families::SANS
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_css_font_features() {
+        let css = presets::NUMERIC.to_css();
+        assert!(css.ends_with("font-feature-settings: \"tnum\";\n"), "{css}");
+        assert!(!presets::BODY.to_css().contains("font-feature-settings"));
+
+        let styled = TextStyle {
+            font_features: &[features::STYLISTIC_SET_1, features::TABULAR_NUMS],
+            ..presets::BODY
+        };
+        assert!(styled
+            .to_css()
+            .contains("font-feature-settings: \"ss01\", \"tnum\";\n"));
+    }
+
+    #[test]
+    fn test_new_and_with_features() {
+        let style = TextStyle::new(families::SANS, 14, weights::MEDIUM, 1.4, 0.0);
+        assert_eq!(style.size, 14);
+        assert!(style.font_features.is_empty());
+
+        let style = style.with_features(&[features::SLASHED_ZERO]);
+        assert_eq!(style.font_features, [features::SLASHED_ZERO]);
+        assert_eq!(style.weight, weights::MEDIUM);
+        assert_eq!(presets::NUMERIC.font_features, [features::TABULAR_NUMS]);
+        assert_eq!(presets::NUMERIC.size, presets::BODY.size);
+    }
+
+    #[test]
+    fn test_terminal_metrics() {
+        assert_eq!(terminal_metrics(&presets::BODY, 4), (2, 3));
+        assert_eq!(terminal_metrics(&presets::CODE, 8), (1, 1));
+        assert_eq!(terminal_metrics(&presets::LABEL, 0), (7, 9));
+    }
+
+    #[test]
+    fn test_scaled() {
+        let large = presets::BODY.scaled(1.5);
+        assert_eq!(large.size, 24);
+        assert_eq!(large.family, presets::BODY.family);
+        assert!((large.line_height - presets::BODY.line_height).abs() < f32::EPSILON);
+        assert_eq!(presets::BODY.scaled(0.0).size, 1);
+        assert_eq!(presets::BODY.scaled(-2.0).size, 1);
+        assert_eq!(presets::BODY.scaled(f32::NAN).size, 1);
+    }
 }